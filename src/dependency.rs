@@ -0,0 +1,94 @@
+// File: dependency.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Topological ordering over plugin dependency graphs. Shared by
+//     `PluginRegistry`'s registration-time validation and its
+//     `resolve_load_order`, so dependencies always initialize before the
+//     plugins that declare them.
+//
+use std::collections::{HashMap, HashSet};
+
+/// Orders the keys of `graph` so that every plugin appears after all of the
+/// plugins it depends on, using Kahn's algorithm. `graph` maps a plugin name
+/// to the names it depends on.
+///
+/// Ties are broken alphabetically so the result is deterministic. Returns
+/// `Err` with the names still unordered (the cycle) if `graph` is not a DAG.
+pub fn topological_order(graph: &HashMap<String, Vec<String>>) -> Result<Vec<String>, Vec<String>> {
+    let mut in_degree: HashMap<&str, usize> = graph.keys().map(|n| (n.as_str(), 0)).collect();
+    let mut dependents: HashMap<&str, Vec<&str>> = HashMap::new();
+
+    for (name, deps) in graph {
+        for dep in deps {
+            *in_degree.entry(name.as_str()).or_insert(0) += 1;
+            dependents.entry(dep.as_str()).or_default().push(name.as_str());
+        }
+    }
+
+    let mut ready: Vec<&str> = in_degree
+        .iter()
+        .filter(|(_, degree)| **degree == 0)
+        .map(|(name, _)| *name)
+        .collect();
+    ready.sort_unstable();
+
+    let mut order = Vec::with_capacity(graph.len());
+    let mut visited: HashSet<&str> = HashSet::new();
+
+    while !ready.is_empty() {
+        ready.sort_unstable();
+        let name = ready.remove(0);
+        if !visited.insert(name) {
+            continue;
+        }
+        order.push(name.to_string());
+
+        if let Some(deps) = dependents.get(name) {
+            for dependent in deps {
+                if let Some(degree) = in_degree.get_mut(dependent) {
+                    *degree -= 1;
+                    if *degree == 0 {
+                        ready.push(dependent);
+                    }
+                }
+            }
+        }
+    }
+
+    if order.len() == graph.len() {
+        Ok(order)
+    } else {
+        let remaining = graph
+            .keys()
+            .filter(|n| !visited.contains(n.as_str()))
+            .cloned()
+            .collect();
+        Err(remaining)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orders_dependencies_before_dependents() {
+        let mut graph = HashMap::new();
+        graph.insert("a".to_string(), vec![]);
+        graph.insert("b".to_string(), vec!["a".to_string()]);
+        graph.insert("c".to_string(), vec!["b".to_string()]);
+
+        let order = topological_order(&graph).unwrap();
+        assert_eq!(order, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn detects_cycles() {
+        let mut graph = HashMap::new();
+        graph.insert("a".to_string(), vec!["b".to_string()]);
+        graph.insert("b".to_string(), vec!["a".to_string()]);
+
+        let cycle = topological_order(&graph).unwrap_err();
+        assert_eq!(cycle.len(), 2);
+    }
+}
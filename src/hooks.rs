@@ -0,0 +1,80 @@
+// File: hooks.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     The hook-dispatch types shared by the `Plugin` trait's default hook
+//     methods and `PluginManager::dispatch`. Turns the `PluginHooks` flags
+//     from inert metadata into the crate's extension mechanism.
+//
+use crate::{Plugin, PluginHooks};
+use auria_core::AuriaResult;
+use std::collections::HashMap;
+
+/// Carries the request/response state a hook observes and may rewrite.
+/// `payload` is an opaque byte buffer (the host and its plugins agree on
+/// its shape out of band, e.g. JSON or MessagePack); `metadata` carries
+/// small out-of-band values like a request id or route name.
+#[derive(Clone, Debug, Default)]
+pub struct HookContext {
+    pub payload: Vec<u8>,
+    pub metadata: HashMap<String, String>,
+}
+
+impl HookContext {
+    pub fn new(payload: Vec<u8>) -> Self {
+        Self { payload, metadata: HashMap::new() }
+    }
+}
+
+/// What a plugin wants to happen after observing a hook.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HookAction {
+    /// Let the remaining plugins in the chain run.
+    Continue,
+    /// Veto the request. Carries a human-readable reason surfaced to the
+    /// caller; no further plugins in the chain run.
+    Abort(String),
+    /// Replace the payload with the carried bytes, which are handed back to
+    /// the caller. No further plugins in the chain run.
+    Replace(Vec<u8>),
+}
+
+/// The points in a request's lifecycle a plugin can observe, mirroring the
+/// flags on [`PluginHooks`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HookKind {
+    PreExecution,
+    PostExecution,
+    PreRouting,
+    PostRouting,
+    OnError,
+    OnRequest,
+    OnResponse,
+}
+
+impl HookKind {
+    /// Whether `hooks` declares interest in this kind of hook.
+    pub fn is_declared(&self, hooks: &PluginHooks) -> bool {
+        match self {
+            HookKind::PreExecution => hooks.pre_execution,
+            HookKind::PostExecution => hooks.post_execution,
+            HookKind::PreRouting => hooks.pre_routing,
+            HookKind::PostRouting => hooks.post_routing,
+            HookKind::OnError => hooks.on_error,
+            HookKind::OnRequest => hooks.on_request,
+            HookKind::OnResponse => hooks.on_response,
+        }
+    }
+
+    /// Calls the matching hook method on `plugin`.
+    pub async fn invoke(&self, plugin: &dyn Plugin, ctx: &mut HookContext) -> AuriaResult<HookAction> {
+        match self {
+            HookKind::PreExecution => plugin.on_pre_execution(ctx).await,
+            HookKind::PostExecution => plugin.on_post_execution(ctx).await,
+            HookKind::PreRouting => plugin.on_pre_routing(ctx).await,
+            HookKind::PostRouting => plugin.on_post_routing(ctx).await,
+            HookKind::OnError => plugin.on_error(ctx).await,
+            HookKind::OnRequest => plugin.on_request(ctx).await,
+            HookKind::OnResponse => plugin.on_response(ctx).await,
+        }
+    }
+}
@@ -7,12 +7,25 @@
 //
 use auria_core::{AuriaError, AuriaResult};
 use async_trait::async_trait;
+use libloading::Library;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
+pub mod dependency;
+pub mod hooks;
+pub mod native;
+pub mod process;
+pub mod signing;
+pub mod state;
+pub mod wasm;
+pub mod watcher;
+
+pub use hooks::{HookAction, HookContext, HookKind};
+pub use state::PluginState;
+
 #[async_trait]
 pub trait Plugin: Send + Sync {
     fn name(&self) -> &str;
@@ -20,6 +33,55 @@ pub trait Plugin: Send + Sync {
     fn plugin_type(&self) -> PluginType;
     async fn initialize(&self) -> AuriaResult<()>;
     async fn shutdown(&self) -> AuriaResult<()>;
+
+    /// Names of plugins that must already be registered before this one.
+    /// `PluginRegistry::register` validates these and `resolve_load_order`
+    /// uses them to initialize dependencies before their dependents.
+    fn dependencies(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Which hook points this plugin wants [`PluginManager::dispatch`] to
+    /// call it for. Defaults to none.
+    fn hooks(&self) -> PluginHooks {
+        PluginHooks::none()
+    }
+
+    /// Called before the request is executed, if this plugin's
+    /// [`PluginHooks::pre_execution`] flag is set.
+    async fn on_pre_execution(&self, _ctx: &mut HookContext) -> AuriaResult<HookAction> {
+        Ok(HookAction::Continue)
+    }
+
+    /// Called after the request is executed, if [`PluginHooks::post_execution`] is set.
+    async fn on_post_execution(&self, _ctx: &mut HookContext) -> AuriaResult<HookAction> {
+        Ok(HookAction::Continue)
+    }
+
+    /// Called before routing, if [`PluginHooks::pre_routing`] is set.
+    async fn on_pre_routing(&self, _ctx: &mut HookContext) -> AuriaResult<HookAction> {
+        Ok(HookAction::Continue)
+    }
+
+    /// Called after routing, if [`PluginHooks::post_routing`] is set.
+    async fn on_post_routing(&self, _ctx: &mut HookContext) -> AuriaResult<HookAction> {
+        Ok(HookAction::Continue)
+    }
+
+    /// Called when an error occurs, if [`PluginHooks::on_error`] is set.
+    async fn on_error(&self, _ctx: &mut HookContext) -> AuriaResult<HookAction> {
+        Ok(HookAction::Continue)
+    }
+
+    /// Called on an incoming request, if [`PluginHooks::on_request`] is set.
+    async fn on_request(&self, _ctx: &mut HookContext) -> AuriaResult<HookAction> {
+        Ok(HookAction::Continue)
+    }
+
+    /// Called on an outgoing response, if [`PluginHooks::on_response`] is set.
+    async fn on_response(&self, _ctx: &mut HookContext) -> AuriaResult<HookAction> {
+        Ok(HookAction::Continue)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -30,6 +92,12 @@ pub enum PluginType {
     Storage,
     Security,
     Monitoring,
+    /// A plugin running inside a sandboxed `wasm32-wasi` guest rather than
+    /// as a trusted native shared library. See [`crate::wasm::WasmPlugin`].
+    Wasm,
+    /// A plugin running as a separate executable, reached over a Unix
+    /// domain socket. See [`crate::process::ProcessPlugin`].
+    Process,
     Custom(String),
 }
 
@@ -96,41 +164,247 @@ impl PluginMetadata {
 
 pub struct PluginRegistry {
     plugins: Arc<RwLock<HashMap<String, PluginEntry>>>,
+    /// Registration order, oldest first. Used to unload plugins in reverse
+    /// order when tearing down the whole manager.
+    order: Arc<RwLock<Vec<String>>>,
+    /// Reverse-dependency map: dependency name -> names of registered
+    /// plugins that declare it as a dependency. Used to reject
+    /// `unregister`/`disable` while an enabled dependent still needs it.
+    dependents: Arc<RwLock<HashMap<String, HashSet<String>>>>,
 }
 
+/// An entry keeps the live plugin object alongside the `Library` that backs
+/// it (if the plugin was loaded from a native shared library).
+///
+/// Field order matters: Rust drops struct fields top-to-bottom, so `plugin`
+/// must be declared before `library`. Dropping the `Library` while `plugin`
+/// (whose vtable and code live in that library) is still alive is undefined
+/// behavior.
 struct PluginEntry {
     metadata: PluginMetadata,
+    plugin: Box<dyn Plugin>,
+    library: Option<Library>,
+    state: PluginState,
+    /// Outcome of signature verification against `PluginConfig::trusted_keys`
+    /// for native/WASM plugins loaded via `load_plugins_from_dir`. `Ok(())`
+    /// for plugins registered directly from code, which have no artifact to
+    /// sign, and for out-of-process plugins, which are not verified.
+    verified: Result<(), String>,
 }
 
 impl PluginRegistry {
     pub fn new() -> Self {
         Self {
             plugins: Arc::new(RwLock::new(HashMap::new())),
+            order: Arc::new(RwLock::new(Vec::new())),
+            dependents: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    pub async fn register<P: Plugin + 'static>(&self, plugin: P) -> AuriaResult<()> {
+        self.register_boxed(Box::new(plugin)).await
+    }
+
+    pub async fn register_boxed(&self, plugin: Box<dyn Plugin>) -> AuriaResult<()> {
+        self.insert_entry(plugin, None, Ok(()), PluginState::Registered).await
+    }
+
+    /// Registers a plugin loaded from a native shared library, recording the
+    /// outcome of signature verification against it. Rejected up front with
+    /// an [`AuriaError`] if `require_signatures` is set and `verified` is an
+    /// `Err` — the plugin is never inserted into the registry.
+    pub(crate) async fn register_native_verified(
+        &self,
+        plugin: Box<dyn Plugin>,
+        library: Library,
+        verified: Result<(), String>,
+        require_signatures: bool,
+    ) -> AuriaResult<()> {
+        Self::enforce_signature_policy(plugin.name(), &verified, require_signatures)?;
+        self.insert_entry(plugin, Some(library), verified, PluginState::Registered).await
+    }
+
+    /// Registers a WASM plugin, recording the outcome of signature
+    /// verification against it. Rejected up front with an [`AuriaError`] if
+    /// `require_signatures` is set and `verified` is an `Err`.
+    pub(crate) async fn register_boxed_verified(
+        &self,
+        plugin: Box<dyn Plugin>,
+        verified: Result<(), String>,
+        require_signatures: bool,
+    ) -> AuriaResult<()> {
+        Self::enforce_signature_policy(plugin.name(), &verified, require_signatures)?;
+        self.insert_entry(plugin, None, verified, PluginState::Registered).await
+    }
+
+    /// Registers a native plugin the caller has already initialized itself
+    /// (hot-reload's pre-swap probe: confirm the replacement comes up
+    /// before tearing down whatever is running), recording it as already
+    /// [`PluginState::Active`] instead of `Registered` so a later
+    /// `initialize` call doesn't run the plugin's `initialize` a second
+    /// time. `verified` records the outcome of signature verification
+    /// against the artifact, same as [`Self::register_native_verified`].
+    pub(crate) async fn register_native_active(
+        &self,
+        plugin: Box<dyn Plugin>,
+        library: Library,
+        verified: Result<(), String>,
+    ) -> AuriaResult<()> {
+        self.insert_entry(plugin, Some(library), verified, PluginState::Active).await
+    }
+
+    /// WASM counterpart to [`Self::register_native_active`].
+    pub(crate) async fn register_boxed_active(
+        &self,
+        plugin: Box<dyn Plugin>,
+        verified: Result<(), String>,
+    ) -> AuriaResult<()> {
+        self.insert_entry(plugin, None, verified, PluginState::Active).await
+    }
+
+    /// Common gate shared by `register_native_verified`/`register_boxed_verified`:
+    /// when signatures are required, a failed or missing one must prevent
+    /// registration rather than just being recorded for later audit.
+    fn enforce_signature_policy(name: &str, verified: &Result<(), String>, require_signatures: bool) -> AuriaResult<()> {
+        if require_signatures {
+            if let Err(reason) = verified {
+                return Err(AuriaError::ExecutionError(format!(
+                    "refusing to register plugin {}: {}",
+                    name, reason
+                )));
+            }
         }
+        Ok(())
     }
 
-    pub async fn register<P: Plugin + 'static>(&self, plugin: &P) -> AuriaResult<()> {
+    async fn insert_entry(
+        &self,
+        plugin: Box<dyn Plugin>,
+        library: Option<Library>,
+        verified: Result<(), String>,
+        initial_state: PluginState,
+    ) -> AuriaResult<()> {
         let name = plugin.name().to_string();
-        
+        let dependencies = plugin.dependencies();
+
         if self.plugins.read().await.contains_key(&name) {
             return Err(AuriaError::ExecutionError(
                 format!("Plugin {} already registered", name),
             ));
         }
-        
-        let metadata = PluginMetadata::new(
+
+        if dependencies.iter().any(|dep| dep == &name) {
+            return Err(AuriaError::ExecutionError(
+                format!("Plugin {} cannot depend on itself (dependency cycle)", name),
+            ));
+        }
+
+        {
+            let plugins = self.plugins.read().await;
+            for dep in &dependencies {
+                if !plugins.contains_key(dep) {
+                    return Err(AuriaError::ExecutionError(format!(
+                        "Plugin {} requires dependency {} which is not registered",
+                        name, dep
+                    )));
+                }
+            }
+        }
+
+        let mut metadata = PluginMetadata::new(
             name.clone(),
             plugin.version().to_string(),
             plugin.plugin_type(),
         );
-        
-        self.plugins.write().await.insert(name, PluginEntry { metadata });
-        
+        metadata.dependencies = dependencies.clone();
+        metadata.hooks = plugin.hooks();
+
+        self.plugins.write().await.insert(
+            name.clone(),
+            PluginEntry { metadata, plugin, library, state: initial_state, verified },
+        );
+        self.order.write().await.push(name.clone());
+
+        let mut dependents = self.dependents.write().await;
+        for dep in dependencies {
+            dependents.entry(dep).or_default().insert(name.clone());
+        }
+
         Ok(())
     }
 
-    pub async fn unregister(&self, name: &str) -> Option<PluginMetadata> {
-        self.plugins.write().await.remove(name).map(|e| e.metadata)
+    /// Names of currently-registered, currently-enabled plugins that declare
+    /// `name` as a dependency.
+    async fn enabled_dependents(&self, name: &str) -> Vec<String> {
+        let Some(dependents) = self.dependents.read().await.get(name).cloned() else {
+            return Vec::new();
+        };
+        let plugins = self.plugins.read().await;
+        dependents
+            .into_iter()
+            .filter(|dependent| plugins.get(dependent).map_or(false, |e| e.metadata.enabled))
+            .collect()
+    }
+
+    /// Shuts the plugin down and unregisters it. The plugin object is
+    /// dropped before its backing `Library` (if any), per field order on
+    /// `PluginEntry`. Fails with a descriptive error if an enabled plugin
+    /// still depends on `name`.
+    pub async fn unregister(&self, name: &str) -> AuriaResult<Option<PluginMetadata>> {
+        if let Some(dependent) = self.enabled_dependents(name).await.into_iter().next() {
+            return Err(AuriaError::ExecutionError(format!(
+                "cannot unregister plugin {}: still in use by enabled plugin {}",
+                name, dependent
+            )));
+        }
+
+        let entry = self.plugins.write().await.remove(name);
+        self.order.write().await.retain(|n| n != name);
+        self.dependents.write().await.remove(name);
+
+        match entry {
+            Some(entry) => {
+                entry.plugin.shutdown().await?;
+                let mut dependents = self.dependents.write().await;
+                for dep in &entry.metadata.dependencies {
+                    if let Some(set) = dependents.get_mut(dep) {
+                        set.remove(name);
+                    }
+                }
+                Ok(Some(entry.metadata))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Returns registered plugin names in dependencies-first topological
+    /// order, so a `PluginManager` can initialize dependencies before their
+    /// dependents.
+    pub async fn resolve_load_order(&self) -> AuriaResult<Vec<String>> {
+        let graph: HashMap<String, Vec<String>> = self
+            .plugins
+            .read()
+            .await
+            .values()
+            .map(|e| (e.metadata.name.clone(), e.metadata.dependencies.clone()))
+            .collect();
+
+        dependency::topological_order(&graph).map_err(|cycle| {
+            AuriaError::ExecutionError(format!(
+                "plugin dependency graph has a cycle among: {}",
+                cycle.join(", ")
+            ))
+        })
+    }
+
+    /// Unregisters every plugin in reverse registration order, so a plugin
+    /// is always torn down before anything that was registered ahead of it.
+    pub async fn unregister_all(&self) -> AuriaResult<()> {
+        let order = self.order.read().await.clone();
+        for name in order.into_iter().rev() {
+            self.unregister(&name).await?;
+        }
+        Ok(())
     }
 
     pub async fn get_metadata(&self, name: &str) -> Option<PluginMetadata> {
@@ -147,6 +421,8 @@ impl PluginRegistry {
                 version: e.metadata.version.clone(),
                 plugin_type: e.metadata.plugin_type.clone(),
                 enabled: e.metadata.enabled,
+                state: e.state.clone(),
+                verified: e.verified.clone(),
             })
             .collect()
     }
@@ -162,15 +438,28 @@ impl PluginRegistry {
                 version: e.metadata.version.clone(),
                 plugin_type: e.metadata.plugin_type.clone(),
                 enabled: e.metadata.enabled,
+                state: e.state.clone(),
+                verified: e.verified.clone(),
             })
             .collect()
     }
 
+    /// Enables a plugin so hook dispatch reaches it again. Rejected while
+    /// the plugin is [`PluginState::Failed`] — call [`Self::reset`] first.
     pub async fn enable(&self, name: &str) -> AuriaResult<()> {
         let mut plugins = self.plugins.write().await;
-        
+
         if let Some(entry) = plugins.get_mut(name) {
+            if let PluginState::Failed(reason) = &entry.state {
+                return Err(AuriaError::ExecutionError(format!(
+                    "cannot enable plugin {}: it failed to initialize ({}); call reset() first",
+                    name, reason
+                )));
+            }
             entry.metadata.enabled = true;
+            if entry.state == PluginState::Disabled {
+                entry.state = PluginState::Active;
+            }
             Ok(())
         } else {
             Err(AuriaError::ExecutionError(
@@ -180,10 +469,20 @@ impl PluginRegistry {
     }
 
     pub async fn disable(&self, name: &str) -> AuriaResult<()> {
+        if let Some(dependent) = self.enabled_dependents(name).await.into_iter().next() {
+            return Err(AuriaError::ExecutionError(format!(
+                "cannot disable plugin {}: still in use by enabled plugin {}",
+                name, dependent
+            )));
+        }
+
         let mut plugins = self.plugins.write().await;
-        
+
         if let Some(entry) = plugins.get_mut(name) {
             entry.metadata.enabled = false;
+            if entry.state == PluginState::Active {
+                entry.state = PluginState::Disabled;
+            }
             Ok(())
         } else {
             Err(AuriaError::ExecutionError(
@@ -192,6 +491,67 @@ impl PluginRegistry {
         }
     }
 
+    /// Calls `initialize` on a registered plugin, transitioning
+    /// [`PluginState::Registered`] -> [`PluginState::Initializing`] ->
+    /// [`PluginState::Active`] on success or -> [`PluginState::Failed`] on
+    /// error. Returns an error without calling `initialize` if the plugin
+    /// is not currently in a state that can legally start initializing.
+    pub async fn initialize(&self, name: &str) -> AuriaResult<()> {
+        {
+            let mut plugins = self.plugins.write().await;
+            let entry = plugins
+                .get_mut(name)
+                .ok_or_else(|| AuriaError::ExecutionError(format!("Plugin {} not found", name)))?;
+            if !entry.state.can_transition_to(&PluginState::Initializing) {
+                return Err(AuriaError::ExecutionError(format!(
+                    "cannot initialize plugin {} from state {}",
+                    name, entry.state
+                )));
+            }
+            entry.state = PluginState::Initializing;
+        }
+
+        let result = {
+            let plugins = self.plugins.read().await;
+            let entry = plugins.get(name).expect("entry present: checked under write lock above");
+            entry.plugin.initialize().await
+        };
+
+        let mut plugins = self.plugins.write().await;
+        if let Some(entry) = plugins.get_mut(name) {
+            entry.state = match &result {
+                Ok(()) => PluginState::Active,
+                Err(e) => PluginState::Failed(e.to_string()),
+            };
+        }
+        result
+    }
+
+    /// Explicitly clears a [`PluginState::Failed`] plugin back to
+    /// [`PluginState::Registered`] so it can be initialized again. Errors
+    /// if the plugin is not currently `Failed`.
+    pub async fn reset(&self, name: &str) -> AuriaResult<()> {
+        let mut plugins = self.plugins.write().await;
+        let entry = plugins
+            .get_mut(name)
+            .ok_or_else(|| AuriaError::ExecutionError(format!("Plugin {} not found", name)))?;
+
+        if !entry.state.can_transition_to(&PluginState::Registered) {
+            return Err(AuriaError::ExecutionError(format!(
+                "cannot reset plugin {} from state {}",
+                name, entry.state
+            )));
+        }
+        entry.state = PluginState::Registered;
+        entry.metadata.enabled = false;
+        Ok(())
+    }
+
+    /// Current lifecycle state of a registered plugin.
+    pub async fn plugin_state(&self, name: &str) -> Option<PluginState> {
+        self.plugins.read().await.get(name).map(|e| e.state.clone())
+    }
+
     pub async fn is_enabled(&self, name: &str) -> bool {
         self.plugins
             .read()
@@ -200,6 +560,125 @@ impl PluginRegistry {
             .map(|e| e.metadata.enabled)
             .unwrap_or(false)
     }
+
+    /// Walks every enabled, [`PluginState::Active`] plugin that declared
+    /// interest in `kind`, in registration order, calling its matching hook
+    /// method. A plugin that failed to initialize (or was reset/disabled)
+    /// is skipped even if `metadata.enabled` is still `true`, so a failed
+    /// plugin never has its hook methods invoked. Stops and returns as soon
+    /// as a plugin returns anything other than [`HookAction::Continue`], so
+    /// a middleware/security plugin can veto or rewrite the request before
+    /// any later plugin in the chain sees it.
+    pub async fn dispatch_hook(&self, kind: HookKind, ctx: &mut HookContext) -> AuriaResult<HookAction> {
+        let order = self.order.read().await.clone();
+        let plugins = self.plugins.read().await;
+
+        for name in order {
+            let Some(entry) = plugins.get(&name) else { continue };
+            if !entry.metadata.enabled
+                || entry.state != PluginState::Active
+                || !kind.is_declared(&entry.metadata.hooks)
+            {
+                continue;
+            }
+
+            match kind.invoke(entry.plugin.as_ref(), ctx).await? {
+                HookAction::Continue => continue,
+                action => return Ok(action),
+            }
+        }
+
+        Ok(HookAction::Continue)
+    }
+
+    /// Names of currently-registered plugins that declare `name` as a
+    /// dependency, regardless of enabled state.
+    pub async fn dependents_of(&self, name: &str) -> Vec<String> {
+        self.dependents
+            .read()
+            .await
+            .get(name)
+            .map(|set| set.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Re-runs `initialize` on an already-registered plugin without
+    /// reloading or replacing it. Used after a dependency is hot-reloaded,
+    /// so dependents can re-acquire whatever they obtained from it.
+    pub async fn reinitialize(&self, name: &str) -> AuriaResult<()> {
+        let plugins = self.plugins.read().await;
+        match plugins.get(name) {
+            Some(entry) => entry.plugin.initialize().await,
+            None => Err(AuriaError::ExecutionError(format!("Plugin {} not found", name))),
+        }
+    }
+
+    /// Replaces an already-registered native plugin's instance in place,
+    /// keeping the same registration order position. The old plugin is
+    /// shut down and dropped together with its `Library` inside this call,
+    /// which the caller reaches only once the replacement is constructed
+    /// and initialized, so a caller that fails to construct the replacement
+    /// never reaches this method and the previous version keeps running.
+    pub(crate) async fn replace_native(
+        &self,
+        name: &str,
+        plugin: Box<dyn Plugin>,
+        library: Library,
+        verified: Result<(), String>,
+    ) -> AuriaResult<()> {
+        let old = self.plugins.write().await.remove(name);
+        let Some(old) = old else {
+            return self.insert_entry_named(name, plugin, Some(library), verified).await;
+        };
+
+        old.plugin.shutdown().await?;
+
+        let mut metadata = old.metadata;
+        metadata.dependencies = plugin.dependencies();
+        metadata.hooks = plugin.hooks();
+        metadata.version = plugin.version().to_string();
+
+        self.plugins.write().await.insert(
+            name.to_string(),
+            PluginEntry {
+                metadata,
+                plugin,
+                library: Some(library),
+                state: PluginState::Active,
+                verified,
+            },
+        );
+
+        Ok(())
+    }
+
+    async fn insert_entry_named(
+        &self,
+        name: &str,
+        plugin: Box<dyn Plugin>,
+        library: Option<Library>,
+        verified: Result<(), String>,
+    ) -> AuriaResult<()> {
+        if plugin.name() != name {
+            return Err(AuriaError::ExecutionError(format!(
+                "reloaded plugin reports name {} but was expected to be {}",
+                plugin.name(),
+                name
+            )));
+        }
+        // Only called from `replace_native`, whose contract is that the
+        // caller already initialized `plugin` before handing it over.
+        self.insert_entry(plugin, library, verified, PluginState::Active).await
+    }
+
+    /// Signature-verification outcome recorded for a registered plugin, if
+    /// any. `Ok(())` for plugins registered directly from code and for
+    /// out-of-process plugins, neither of which is signature-checked. A
+    /// native/WASM artifact loaded while `trusted_keys` is empty records an
+    /// `Err`.
+    pub async fn verification_status(&self, name: &str) -> Option<Result<(), String>> {
+        self.plugins.read().await.get(name).map(|e| e.verified.clone())
+    }
 }
 
 impl Default for PluginRegistry {
@@ -214,11 +693,15 @@ pub struct PluginInfo {
     pub version: String,
     pub plugin_type: PluginType,
     pub enabled: bool,
+    pub state: PluginState,
+    /// Signature-verification outcome; see [`PluginRegistry::verification_status`].
+    pub verified: Result<(), String>,
 }
 
 pub struct PluginManager {
     registry: Arc<PluginRegistry>,
     config: PluginConfig,
+    hot_reload: Option<watcher::HotReloadWatcher>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -226,6 +709,13 @@ pub struct PluginConfig {
     pub plugin_dirs: Vec<PathBuf>,
     pub auto_enable: bool,
     pub enable_hot_reload: bool,
+    /// Hex-encoded ed25519 public keys trusted to sign native/WASM plugin
+    /// artifacts. See [`PluginManager::load_plugins_from_dir`].
+    pub trusted_keys: Vec<String>,
+    /// When set, a native/WASM artifact under `plugin_dirs` without a valid
+    /// signature from a key in `trusted_keys` is refused registration
+    /// rather than loaded with its unverified status merely recorded.
+    pub require_signatures: bool,
 }
 
 impl Default for PluginConfig {
@@ -234,6 +724,35 @@ impl Default for PluginConfig {
             plugin_dirs: Vec::new(),
             auto_enable: true,
             enable_hot_reload: false,
+            trusted_keys: Vec::new(),
+            require_signatures: false,
+        }
+    }
+}
+
+/// An artifact discovered by [`PluginManager::load_plugins_from_dir`] that
+/// has been loaded but not yet registered, pending its dependencies
+/// becoming available.
+enum PendingPlugin {
+    Native { plugin: Box<dyn Plugin>, library: Library, verified: Result<(), String> },
+    Wasm { plugin: Box<dyn Plugin>, verified: Result<(), String> },
+    Process { plugin: Box<dyn Plugin> },
+}
+
+impl PendingPlugin {
+    fn name(&self) -> String {
+        match self {
+            PendingPlugin::Native { plugin, .. } => plugin.name().to_string(),
+            PendingPlugin::Wasm { plugin, .. } => plugin.name().to_string(),
+            PendingPlugin::Process { plugin } => plugin.name().to_string(),
+        }
+    }
+
+    fn dependencies(&self) -> Vec<String> {
+        match self {
+            PendingPlugin::Native { plugin, .. } => plugin.dependencies(),
+            PendingPlugin::Wasm { plugin, .. } => plugin.dependencies(),
+            PendingPlugin::Process { plugin } => plugin.dependencies(),
         }
     }
 }
@@ -243,6 +762,7 @@ impl PluginManager {
         Self {
             registry: Arc::new(PluginRegistry::new()),
             config: PluginConfig::default(),
+            hot_reload: None,
         }
     }
 
@@ -250,6 +770,7 @@ impl PluginManager {
         Self {
             registry: Arc::new(PluginRegistry::new()),
             config,
+            hot_reload: None,
         }
     }
 
@@ -257,34 +778,215 @@ impl PluginManager {
         self.registry.clone()
     }
 
-    pub async fn register_plugin<P: Plugin + 'static>(&self, plugin: &P) -> AuriaResult<()> {
+    /// Starts watching `config.plugin_dirs` for plugin changes if
+    /// `config.enable_hot_reload` is set; a no-op otherwise (and if the
+    /// watcher is already running). Must be called from within a Tokio
+    /// runtime, since it spawns a background task.
+    ///
+    /// Hot-reloaded native/WASM artifacts are checked against
+    /// `config.trusted_keys`/`config.require_signatures`, same as
+    /// [`Self::load_plugins_from_dir`] — dropping an unsigned artifact into
+    /// a watched directory does not bypass the signature policy.
+    pub fn start_hot_reload(&mut self) -> AuriaResult<()> {
+        if self.config.enable_hot_reload && self.hot_reload.is_none() {
+            let trusted_keys = signing::parse_trusted_keys(&self.config.trusted_keys);
+            self.hot_reload = Some(watcher::watch(
+                self.config.plugin_dirs.clone(),
+                self.registry.clone(),
+                trusted_keys,
+                self.config.require_signatures,
+            )?);
+        }
+        Ok(())
+    }
+
+    pub async fn register_plugin<P: Plugin + 'static>(&self, plugin: P) -> AuriaResult<()> {
         self.registry.register(plugin).await
     }
 
-    pub async fn unregister_plugin(&self, name: &str) -> Option<PluginMetadata> {
+    pub async fn unregister_plugin(&self, name: &str) -> AuriaResult<Option<PluginMetadata>> {
         self.registry.unregister(name).await
     }
 
+    /// Registered plugin names in dependencies-first order.
+    pub async fn resolve_load_order(&self) -> AuriaResult<Vec<String>> {
+        self.registry.resolve_load_order().await
+    }
+
+    /// Dispatches a hook to every enabled plugin that declared interest in
+    /// it, stopping early if a plugin aborts or replaces the payload. See
+    /// [`PluginRegistry::dispatch_hook`].
+    pub async fn dispatch(&self, kind: HookKind, ctx: &mut HookContext) -> AuriaResult<HookAction> {
+        self.registry.dispatch_hook(kind, ctx).await
+    }
+
+    /// Discovers and loads plugins from `dir`, returning the number
+    /// successfully registered.
+    ///
+    /// Three backends are recognized by file name:
+    /// - `.so`/`.dll`/`.dylib`: opened with `libloading`, validated against
+    ///   [`native::PLUGIN_ABI_VERSION`], constructed via its exported
+    ///   `_auria_plugin_create`, initialized, and registered. The `Library`
+    ///   is kept alive inside the registry for as long as the plugin stays
+    ///   registered.
+    /// - `.wasm`: compiled and instantiated in a sandboxed `wasm32-wasi`
+    ///   runtime via [`wasm::WasmPlugin`], for untrusted third-party plugins
+    ///   that should not run as arbitrary native code.
+    /// - `*.plugin.json`: a [`process::ProcessManifest`] describing an
+    ///   out-of-process plugin; the named executable is spawned and reached
+    ///   over a Unix domain socket via [`process::ProcessPlugin`], so a
+    ///   crash in the plugin can't take the host down with it.
+    ///
+    /// A plugin that can't be loaded at all (bad artifact, missing
+    /// dependency) is skipped. A plugin that loads but fails `initialize`
+    /// is still registered, in [`PluginState::Failed`], so operators can
+    /// see it and why via `list_plugins`; it does not count towards the
+    /// returned total.
+    ///
+    /// Native and WASM artifacts are checked against a detached `.sig` file
+    /// and `config.trusted_keys` (see [`signing::verify_artifact`]). If
+    /// `config.require_signatures` is set, a missing or invalid signature
+    /// is treated like any other load failure and the artifact is skipped;
+    /// otherwise it is still loaded, with the outcome recorded on
+    /// [`PluginInfo::verified`] for audit. Out-of-process plugins are not
+    /// currently covered by signature verification.
+    ///
+    /// Registration does not simply follow `read_dir`'s enumeration order:
+    /// every recognized artifact in `dir` is loaded first, then registered
+    /// in dependency order by repeatedly sweeping the loaded set and
+    /// registering whatever now has all its dependencies satisfied, until a
+    /// full sweep makes no further progress. This lets a dependent plugin's
+    /// file be discovered before its dependency's file and still load
+    /// successfully, the same way [`PluginRegistry::resolve_load_order`]
+    /// already orders plugins registered directly from code.
     pub async fn load_plugins_from_dir(&self, dir: &PathBuf) -> AuriaResult<usize> {
         let mut loaded = 0;
-        
+
         if !dir.exists() {
             return Ok(0);
         }
-        
+
         let entries = match std::fs::read_dir(dir) {
             Ok(e) => e,
             Err(_) => return Ok(0),
         };
-        
+
+        let trusted_keys = signing::parse_trusted_keys(&self.config.trusted_keys);
+
+        // Phase 1: load every recognized artifact without registering it
+        // yet, so registration can follow the dependency graph rather than
+        // directory-enumeration order.
+        let mut pending: Vec<PendingPlugin> = Vec::new();
+
         for entry in entries.flatten() {
             let path = entry.path();
-            
-            if path.extension().map_or(false, |e| e == "so" || e == "dll" || e == "dylib") {
-                loaded += 1;
+
+            if native::is_native_plugin_path(&path) {
+                // Check the signature before the artifact is ever loaded:
+                // `load_native_plugin` dlopens the shared library and calls
+                // its exported constructor, both of which run arbitrary
+                // code from the artifact. An artifact that fails a required
+                // signature check must never reach that point.
+                let verified = signing::verify_artifact(&path, &trusted_keys);
+                if self.config.require_signatures && verified.is_err() {
+                    continue;
+                }
+
+                // Safety: we only load files the operator placed in a
+                // configured plugin directory.
+                let loaded_plugin = match unsafe { native::load_native_plugin(&path) } {
+                    Ok(p) => p,
+                    Err(_) => continue,
+                };
+
+                pending.push(PendingPlugin::Native {
+                    plugin: loaded_plugin.plugin,
+                    library: loaded_plugin.library,
+                    verified,
+                });
+            } else if wasm::is_wasm_plugin_path(&path) {
+                // Same ordering requirement as the native path above: check
+                // the signature before the module is compiled/instantiated.
+                let verified = signing::verify_artifact(&path, &trusted_keys);
+                if self.config.require_signatures && verified.is_err() {
+                    continue;
+                }
+
+                let plugin = match wasm::WasmPlugin::load(&path, wasm::WasmPluginConfig::default()).await {
+                    Ok(p) => p,
+                    Err(_) => continue,
+                };
+
+                pending.push(PendingPlugin::Wasm { plugin: Box::new(plugin), verified });
+            } else if process::is_process_manifest_path(&path) {
+                let manifest_bytes = match std::fs::read(&path) {
+                    Ok(b) => b,
+                    Err(_) => continue,
+                };
+                let manifest: process::ProcessManifest = match serde_json::from_slice(&manifest_bytes) {
+                    Ok(m) => m,
+                    Err(_) => continue,
+                };
+
+                let plugin = match process::ProcessPlugin::spawn(&manifest).await {
+                    Ok(p) => p,
+                    Err(_) => continue,
+                };
+
+                pending.push(PendingPlugin::Process { plugin: Box::new(plugin) });
             }
         }
-        
+
+        // Phase 2: register/initialize in dependency order. A plugin whose
+        // dependency is still pending (not yet registered, e.g. because its
+        // file sorts later) is deferred to the next sweep instead of being
+        // dropped. Whatever is still pending once a sweep registers
+        // nothing is left unregistered, same as any other unmet dependency.
+        while !pending.is_empty() {
+            let mut next_pending = Vec::new();
+            let mut made_progress = false;
+
+            for candidate in pending {
+                let mut deps_ready = true;
+                for dep in &candidate.dependencies() {
+                    if self.registry.get_metadata(dep).await.is_none() {
+                        deps_ready = false;
+                        break;
+                    }
+                }
+
+                if !deps_ready {
+                    next_pending.push(candidate);
+                    continue;
+                }
+
+                made_progress = true;
+                let name = candidate.name();
+                let registered = match candidate {
+                    PendingPlugin::Native { plugin, library, verified } => {
+                        self.registry
+                            .register_native_verified(plugin, library, verified, self.config.require_signatures)
+                            .await
+                    }
+                    PendingPlugin::Wasm { plugin, verified } => {
+                        self.registry
+                            .register_boxed_verified(plugin, verified, self.config.require_signatures)
+                            .await
+                    }
+                    PendingPlugin::Process { plugin } => self.registry.register_boxed(plugin).await,
+                };
+
+                if registered.is_ok() && self.registry.initialize(&name).await.is_ok() {
+                    loaded += 1;
+                }
+            }
+
+            if !made_progress {
+                break;
+            }
+            pending = next_pending;
+        }
+
         Ok(loaded)
     }
 
@@ -293,11 +995,16 @@ impl PluginManager {
     }
 
     pub async fn get_plugin_info(&self, name: &str) -> Option<PluginInfo> {
-        self.registry.get_metadata(name).await.map(|m| PluginInfo {
-            name: m.name,
-            version: m.version,
-            plugin_type: m.plugin_type,
-            enabled: m.enabled,
+        let metadata = self.registry.get_metadata(name).await?;
+        let state = self.registry.plugin_state(name).await.unwrap_or(PluginState::Unloaded);
+        let verified = self.registry.verification_status(name).await.unwrap_or(Ok(()));
+        Some(PluginInfo {
+            name: metadata.name,
+            version: metadata.version,
+            plugin_type: metadata.plugin_type,
+            enabled: metadata.enabled,
+            state,
+            verified,
         })
     }
 
@@ -308,6 +1015,18 @@ impl PluginManager {
     pub async fn disable_plugin(&self, name: &str) -> AuriaResult<()> {
         self.registry.disable(name).await
     }
+
+    /// Runs `initialize` on a registered plugin, recording the outcome as
+    /// its [`PluginState`].
+    pub async fn initialize_plugin(&self, name: &str) -> AuriaResult<()> {
+        self.registry.initialize(name).await
+    }
+
+    /// Clears a [`PluginState::Failed`] plugin back to `Registered` so it
+    /// can be initialized again.
+    pub async fn reset_plugin(&self, name: &str) -> AuriaResult<()> {
+        self.registry.reset(name).await
+    }
 }
 
 impl Default for PluginManager {
@@ -372,7 +1091,7 @@ mod tests {
         }
 
         let plugin = TestPlugin;
-        registry.register(&plugin).await.unwrap();
+        registry.register(plugin).await.unwrap();
         
         let plugins = registry.list_plugins().await;
         assert_eq!(plugins.len(), 1);
@@ -394,7 +1113,7 @@ mod tests {
         }
 
         let plugin = TestPlugin;
-        registry.register(&plugin).await.unwrap();
+        registry.register(plugin).await.unwrap();
         
         registry.disable("test").await.unwrap();
         assert!(!registry.is_enabled("test").await);
@@ -419,7 +1138,7 @@ mod tests {
         }
 
         let plugin = TestPlugin;
-        manager.register_plugin(&plugin).await.unwrap();
+        manager.register_plugin(plugin).await.unwrap();
         
         let plugins = manager.list_plugins().await;
         assert_eq!(plugins.len(), 1);
@@ -450,8 +1169,8 @@ mod tests {
             async fn shutdown(&self) -> AuriaResult<()> { Ok(()) }
         }
 
-        registry.register(&BackendTestPlugin).await.unwrap();
-        registry.register(&RouterTestPlugin).await.unwrap();
+        registry.register(BackendTestPlugin).await.unwrap();
+        registry.register(RouterTestPlugin).await.unwrap();
         
         let backends = registry.list_by_type(PluginType::Backend).await;
         assert_eq!(backends.len(), 1);
@@ -485,12 +1204,260 @@ mod tests {
         }
 
         let plugin = TestPlugin;
-        registry.register(&plugin).await.unwrap();
+        registry.register(plugin).await.unwrap();
         
-        let removed = registry.unregister("test").await;
+        let removed = registry.unregister("test").await.unwrap();
         assert!(removed.is_some());
         
         let plugins = registry.list_plugins().await;
         assert_eq!(plugins.len(), 0);
     }
+
+    #[tokio::test]
+    async fn test_unregister_all_reverse_order() {
+        let registry = PluginRegistry::new();
+
+        struct TestPlugin(&'static str, Arc<std::sync::Mutex<Vec<&'static str>>>);
+
+        #[async_trait]
+        impl Plugin for TestPlugin {
+            fn name(&self) -> &str { self.0 }
+            fn version(&self) -> &str { "1.0.0" }
+            fn plugin_type(&self) -> PluginType { PluginType::Custom("test".to_string()) }
+            async fn initialize(&self) -> AuriaResult<()> { Ok(()) }
+            async fn shutdown(&self) -> AuriaResult<()> {
+                self.1.lock().unwrap().push(self.0);
+                Ok(())
+            }
+        }
+
+        let shutdowns = Arc::new(std::sync::Mutex::new(Vec::new()));
+        registry.register(TestPlugin("first", shutdowns.clone())).await.unwrap();
+        registry.register(TestPlugin("second", shutdowns.clone())).await.unwrap();
+
+        registry.unregister_all().await.unwrap();
+
+        let plugins = registry.list_plugins().await;
+        assert_eq!(plugins.len(), 0);
+        assert_eq!(*shutdowns.lock().unwrap(), vec!["second", "first"]);
+    }
+
+    struct DependentPlugin {
+        name: &'static str,
+        dependencies: Vec<String>,
+    }
+
+    #[async_trait]
+    impl Plugin for DependentPlugin {
+        fn name(&self) -> &str { self.name }
+        fn version(&self) -> &str { "1.0.0" }
+        fn plugin_type(&self) -> PluginType { PluginType::Custom("test".to_string()) }
+        async fn initialize(&self) -> AuriaResult<()> { Ok(()) }
+        async fn shutdown(&self) -> AuriaResult<()> { Ok(()) }
+        fn dependencies(&self) -> Vec<String> { self.dependencies.clone() }
+    }
+
+    #[tokio::test]
+    async fn test_register_rejects_missing_dependency() {
+        let registry = PluginRegistry::new();
+
+        let plugin = DependentPlugin { name: "dependent", dependencies: vec!["base".to_string()] };
+        let err = registry.register(plugin).await.unwrap_err();
+        assert!(err.to_string().contains("not registered"));
+    }
+
+    #[tokio::test]
+    async fn test_register_rejects_self_dependency() {
+        let registry = PluginRegistry::new();
+
+        let plugin = DependentPlugin { name: "looped", dependencies: vec!["looped".to_string()] };
+        let err = registry.register(plugin).await.unwrap_err();
+        assert!(err.to_string().contains("cycle"));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_load_order_is_dependencies_first() {
+        let registry = PluginRegistry::new();
+
+        registry
+            .register(DependentPlugin { name: "base", dependencies: vec![] })
+            .await
+            .unwrap();
+        registry
+            .register(DependentPlugin { name: "dependent", dependencies: vec!["base".to_string()] })
+            .await
+            .unwrap();
+
+        let order = registry.resolve_load_order().await.unwrap();
+        let base_index = order.iter().position(|n| n == "base").unwrap();
+        let dependent_index = order.iter().position(|n| n == "dependent").unwrap();
+        assert!(base_index < dependent_index);
+    }
+
+    #[tokio::test]
+    async fn test_unregister_fails_while_in_use() {
+        let registry = PluginRegistry::new();
+
+        registry
+            .register(DependentPlugin { name: "base", dependencies: vec![] })
+            .await
+            .unwrap();
+        registry
+            .register(DependentPlugin { name: "dependent", dependencies: vec!["base".to_string()] })
+            .await
+            .unwrap();
+
+        let err = registry.unregister("base").await.unwrap_err();
+        assert!(err.to_string().contains("in use"));
+
+        registry.unregister("dependent").await.unwrap();
+        registry.unregister("base").await.unwrap();
+    }
+
+    struct AbortingPlugin;
+
+    #[async_trait]
+    impl Plugin for AbortingPlugin {
+        fn name(&self) -> &str { "aborting" }
+        fn version(&self) -> &str { "1.0.0" }
+        fn plugin_type(&self) -> PluginType { PluginType::Security }
+        async fn initialize(&self) -> AuriaResult<()> { Ok(()) }
+        async fn shutdown(&self) -> AuriaResult<()> { Ok(()) }
+        fn hooks(&self) -> PluginHooks {
+            PluginHooks { on_request: true, ..PluginHooks::none() }
+        }
+        async fn on_request(&self, _ctx: &mut HookContext) -> AuriaResult<HookAction> {
+            Ok(HookAction::Abort("blocked by policy".to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_hook_stops_on_abort() {
+        let manager = PluginManager::new();
+        manager.register_plugin(AbortingPlugin).await.unwrap();
+        manager.initialize_plugin("aborting").await.unwrap();
+
+        let mut ctx = HookContext::new(b"payload".to_vec());
+        let action = manager.dispatch(HookKind::OnRequest, &mut ctx).await.unwrap();
+        assert_eq!(action, HookAction::Abort("blocked by policy".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_hook_ignores_undeclared_plugins() {
+        let manager = PluginManager::new();
+
+        struct TestPlugin;
+        #[async_trait]
+        impl Plugin for TestPlugin {
+            fn name(&self) -> &str { "test" }
+            fn version(&self) -> &str { "1.0.0" }
+            fn plugin_type(&self) -> PluginType { PluginType::Custom("test".to_string()) }
+            async fn initialize(&self) -> AuriaResult<()> { Ok(()) }
+            async fn shutdown(&self) -> AuriaResult<()> { Ok(()) }
+        }
+
+        manager.register_plugin(TestPlugin).await.unwrap();
+
+        let mut ctx = HookContext::default();
+        let action = manager.dispatch(HookKind::OnRequest, &mut ctx).await.unwrap();
+        assert_eq!(action, HookAction::Continue);
+    }
+
+    struct FailingInitPlugin;
+
+    #[async_trait]
+    impl Plugin for FailingInitPlugin {
+        fn name(&self) -> &str { "failing-init" }
+        fn version(&self) -> &str { "1.0.0" }
+        fn plugin_type(&self) -> PluginType { PluginType::Custom("test".to_string()) }
+        async fn initialize(&self) -> AuriaResult<()> {
+            Err(AuriaError::ExecutionError("boom".to_string()))
+        }
+        async fn shutdown(&self) -> AuriaResult<()> { Ok(()) }
+    }
+
+    #[tokio::test]
+    async fn test_failed_initialize_records_state_and_blocks_enable() {
+        let registry = PluginRegistry::new();
+        registry.register(FailingInitPlugin).await.unwrap();
+
+        let err = registry.initialize("failing-init").await.unwrap_err();
+        assert!(err.to_string().contains("boom"));
+        assert_eq!(
+            registry.plugin_state("failing-init").await,
+            Some(PluginState::Failed("boom".to_string()))
+        );
+
+        let enable_err = registry.enable("failing-init").await.unwrap_err();
+        assert!(enable_err.to_string().contains("reset"));
+
+        registry.reset("failing-init").await.unwrap();
+        assert_eq!(registry.plugin_state("failing-init").await, Some(PluginState::Registered));
+    }
+
+    struct FailingHookPlugin;
+
+    #[async_trait]
+    impl Plugin for FailingHookPlugin {
+        fn name(&self) -> &str { "failing-hook" }
+        fn version(&self) -> &str { "1.0.0" }
+        fn plugin_type(&self) -> PluginType { PluginType::Custom("test".to_string()) }
+        async fn initialize(&self) -> AuriaResult<()> {
+            Err(AuriaError::ExecutionError("boom".to_string()))
+        }
+        async fn shutdown(&self) -> AuriaResult<()> { Ok(()) }
+        fn hooks(&self) -> PluginHooks {
+            PluginHooks { on_request: true, ..PluginHooks::none() }
+        }
+        async fn on_request(&self, _ctx: &mut HookContext) -> AuriaResult<HookAction> {
+            Ok(HookAction::Abort("should never run".to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_hook_skips_plugin_that_failed_to_initialize() {
+        let manager = PluginManager::new();
+        manager.register_plugin(FailingHookPlugin).await.unwrap();
+        manager.initialize_plugin("failing-hook").await.unwrap_err();
+
+        let mut ctx = HookContext::default();
+        let action = manager.dispatch(HookKind::OnRequest, &mut ctx).await.unwrap();
+        assert_eq!(action, HookAction::Continue);
+    }
+
+    struct SignableTestPlugin;
+
+    #[async_trait]
+    impl Plugin for SignableTestPlugin {
+        fn name(&self) -> &str { "signable" }
+        fn version(&self) -> &str { "1.0.0" }
+        fn plugin_type(&self) -> PluginType { PluginType::Custom("test".to_string()) }
+        async fn initialize(&self) -> AuriaResult<()> { Ok(()) }
+        async fn shutdown(&self) -> AuriaResult<()> { Ok(()) }
+    }
+
+    #[tokio::test]
+    async fn test_register_boxed_verified_rejects_unverified_when_required() {
+        let registry = PluginRegistry::new();
+
+        let err = registry
+            .register_boxed_verified(Box::new(SignableTestPlugin), Err("no signature file".to_string()), true)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("no signature file"));
+        assert!(registry.get_metadata("signable").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_register_boxed_verified_records_unverified_status_when_not_required() {
+        let registry = PluginRegistry::new();
+
+        registry
+            .register_boxed_verified(Box::new(SignableTestPlugin), Err("no signature file".to_string()), false)
+            .await
+            .unwrap();
+
+        let info = registry.list_plugins().await;
+        assert_eq!(info[0].verified, Err("no signature file".to_string()));
+    }
 }
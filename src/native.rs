@@ -0,0 +1,194 @@
+// File: native.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Native (shared-library) plugin backend.
+//     Defines the C-ABI export contract that compiled plugins must implement
+//     and the host-side loader that opens a `Library`, validates its ABI
+//     version, and constructs the boxed `Plugin` trait object.
+//
+use crate::Plugin;
+use auria_core::{AuriaError, AuriaResult};
+use libloading::{Library, Symbol};
+use std::path::Path;
+
+/// ABI contract version. Bump this whenever the exported symbol signatures
+/// change in a way that is not backwards compatible; plugins built against a
+/// different version are rejected at load time instead of being allowed to
+/// crash the host.
+pub const PLUGIN_ABI_VERSION: u32 = 1;
+
+/// Name of the exported constructor every native plugin must provide.
+pub const PLUGIN_CREATE_SYMBOL: &[u8] = b"_auria_plugin_create";
+
+/// Name of the exported ABI version accessor every native plugin must provide.
+pub const PLUGIN_ABI_VERSION_SYMBOL: &[u8] = b"_auria_plugin_abi_version";
+
+/// Signature of the constructor a plugin library exports.
+///
+/// The returned pointer is produced by `Box::into_raw` on the guest side and
+/// must be reconstructed with `Box::from_raw` on the host side exactly once.
+pub type PluginCreateFn = unsafe extern "C" fn() -> *mut dyn Plugin;
+
+/// Signature of the ABI version accessor a plugin library exports.
+pub type PluginAbiVersionFn = unsafe extern "C" fn() -> u32;
+
+/// Declares the exports a crate needs in order to be loadable as an AURIA
+/// native plugin. Plugin authors call this once, at crate root, with an
+/// expression that builds a fresh boxed trait object.
+///
+/// ```ignore
+/// auria_plugin::export_plugin!(|| Box::new(MyPlugin::default()));
+/// ```
+#[macro_export]
+macro_rules! export_plugin {
+    ($create:expr) => {
+        #[no_mangle]
+        pub extern "C" fn _auria_plugin_abi_version() -> u32 {
+            $crate::native::PLUGIN_ABI_VERSION
+        }
+
+        #[no_mangle]
+        pub extern "C" fn _auria_plugin_create() -> *mut dyn $crate::Plugin {
+            let ctor: fn() -> Box<dyn $crate::Plugin> = $create;
+            Box::into_raw(ctor())
+        }
+    };
+}
+
+/// A plugin loaded from a native shared library, paired with the `Library`
+/// that backs it.
+///
+/// The `Library` must outlive `plugin`: its code and vtables are what the
+/// trait object's method calls resolve to, so dropping it first is undefined
+/// behavior. Keep both fields together and never move `plugin` out without
+/// `library`.
+pub struct LoadedNativePlugin {
+    pub plugin: Box<dyn Plugin>,
+    pub library: Library,
+}
+
+/// Opens `path` as a dynamic library, validates its declared ABI version,
+/// and constructs the plugin it exports.
+///
+/// # Safety
+/// This calls into arbitrary native code via `dlopen`/`LoadLibrary` and
+/// invokes a foreign function pointer. The caller must trust `path` to
+/// contain a well-behaved AURIA plugin.
+pub unsafe fn load_native_plugin(path: &Path) -> AuriaResult<LoadedNativePlugin> {
+    let library = Library::new(path).map_err(|e| {
+        AuriaError::ExecutionError(format!("failed to open plugin library {}: {}", path.display(), e))
+    })?;
+
+    let abi_version: Symbol<PluginAbiVersionFn> = library.get(PLUGIN_ABI_VERSION_SYMBOL).map_err(|e| {
+        AuriaError::ExecutionError(format!(
+            "plugin {} does not export {}: {}",
+            path.display(),
+            String::from_utf8_lossy(PLUGIN_ABI_VERSION_SYMBOL),
+            e
+        ))
+    })?;
+    let declared_version = abi_version();
+    if declared_version != PLUGIN_ABI_VERSION {
+        return Err(AuriaError::ExecutionError(format!(
+            "plugin {} declares ABI version {} but host expects {}",
+            path.display(),
+            declared_version,
+            PLUGIN_ABI_VERSION
+        )));
+    }
+
+    let create: Symbol<PluginCreateFn> = library.get(PLUGIN_CREATE_SYMBOL).map_err(|e| {
+        AuriaError::ExecutionError(format!(
+            "plugin {} does not export {}: {}",
+            path.display(),
+            String::from_utf8_lossy(PLUGIN_CREATE_SYMBOL),
+            e
+        ))
+    })?;
+
+    let raw = create();
+    if raw.is_null() {
+        return Err(AuriaError::ExecutionError(format!(
+            "plugin {} constructor returned a null pointer",
+            path.display()
+        )));
+    }
+    let plugin = Box::from_raw(raw);
+
+    Ok(LoadedNativePlugin { plugin, library })
+}
+
+/// Extensions a path needs in order to be considered a native plugin candidate.
+pub fn is_native_plugin_path(path: &Path) -> bool {
+    path.extension()
+        .map_or(false, |ext| ext == "so" || ext == "dll" || ext == "dylib")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    /// Compiles `source` as a cdylib with `rustc` directly (no Cargo project
+    /// needed) so `load_native_plugin`'s dlopen/ABI-check path can be
+    /// exercised against a real shared library instead of a fake one.
+    fn compile_fixture(dir: &Path, name: &str, source: &str) -> PathBuf {
+        std::fs::create_dir_all(dir).unwrap();
+        let src_path = dir.join(format!("{}.rs", name));
+        std::fs::write(&src_path, source).unwrap();
+        let out_path = dir.join(format!("lib{}.so", name));
+        let status = std::process::Command::new("rustc")
+            .args(["--crate-type", "cdylib", "-o"])
+            .arg(&out_path)
+            .arg(&src_path)
+            .status()
+            .expect("failed to invoke rustc to build native plugin fixture");
+        assert!(status.success(), "fixture {} failed to compile", name);
+        out_path
+    }
+
+    #[test]
+    fn rejects_a_plugin_declaring_the_wrong_abi_version() {
+        let dir = std::env::temp_dir().join(format!("auria-native-test-abi-{}", std::process::id()));
+        let path = compile_fixture(
+            &dir,
+            "wrong_abi",
+            r#"
+                #[no_mangle]
+                pub extern "C" fn _auria_plugin_abi_version() -> u32 { 99 }
+            "#,
+        );
+
+        let err = unsafe { load_native_plugin(&path) }.unwrap_err();
+        assert!(err.to_string().contains("declares ABI version 99"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn rejects_a_plugin_missing_the_create_symbol() {
+        let dir = std::env::temp_dir().join(format!("auria-native-test-no-create-{}", std::process::id()));
+        let source = format!(
+            r#"
+                #[no_mangle]
+                pub extern "C" fn _auria_plugin_abi_version() -> u32 {{ {} }}
+            "#,
+            PLUGIN_ABI_VERSION
+        );
+        let path = compile_fixture(&dir, "no_create", &source);
+
+        let err = unsafe { load_native_plugin(&path) }.unwrap_err();
+        assert!(err.to_string().contains("_auria_plugin_create"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn is_native_plugin_path_matches_known_extensions_only() {
+        assert!(is_native_plugin_path(Path::new("backend.so")));
+        assert!(is_native_plugin_path(Path::new("backend.dll")));
+        assert!(is_native_plugin_path(Path::new("backend.dylib")));
+        assert!(!is_native_plugin_path(Path::new("backend.wasm")));
+        assert!(!is_native_plugin_path(Path::new("manifest.plugin.json")));
+    }
+}
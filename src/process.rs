@@ -0,0 +1,333 @@
+// File: process.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Out-of-process plugin backend. Runs a plugin as a separate executable
+//     and talks to it over a length-prefixed MessagePack RPC protocol on a
+//     Unix domain socket, so plugins can be written in any language and a
+//     crashing plugin can't take the host runtime down with it.
+//
+use crate::{Plugin, PluginHooks, PluginType};
+use auria_core::{AuriaError, AuriaResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::process::{Child, Command};
+use tokio::sync::{oneshot, Mutex};
+
+/// Manifest describing an out-of-process plugin, discovered by
+/// `PluginManager::load_plugins_from_dir` alongside native and WASM
+/// artifacts.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ProcessManifest {
+    pub name: String,
+    pub version: String,
+    pub executable: PathBuf,
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub hooks: PluginHooks,
+}
+
+/// Extension a manifest file needs in order to be considered a process
+/// plugin candidate.
+pub fn is_process_manifest_path(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .map_or(false, |n| n.ends_with(".plugin.json"))
+}
+
+/// Default time a single RPC call may take before the call fails with a
+/// timeout error.
+const DEFAULT_CALL_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Largest RPC response frame accepted from a plugin process. Without this
+/// cap the 4-byte length prefix would be trusted verbatim, letting a
+/// misbehaving or compromised plugin claim a length up to ~4GiB and force a
+/// single giant allocation.
+const MAX_FRAME_LEN: usize = 4 * 1024 * 1024;
+
+#[derive(Serialize)]
+struct RpcRequest<P: Serialize> {
+    id: u64,
+    method: String,
+    params: P,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RpcResponse {
+    id: u64,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+type PendingMap = Arc<Mutex<HashMap<u64, oneshot::Sender<Result<(), String>>>>>;
+
+/// A plugin running as a child process, reached over a Unix domain socket
+/// using length-prefixed MessagePack frames.
+///
+/// `initialize`/`shutdown` (and, eventually, hook dispatch) become RPC
+/// requests: the host assigns a request id, writes `{id, method, params}`,
+/// and awaits the matching `{id, result|error}` response. A background task
+/// owns the read half of the socket and the child handle so it can detect
+/// an unexpected exit and fail every request still in flight instead of
+/// hanging.
+pub struct ProcessPlugin {
+    name: String,
+    version: String,
+    socket_path: PathBuf,
+    writer: Mutex<tokio::net::unix::OwnedWriteHalf>,
+    pending: PendingMap,
+    next_id: AtomicU64,
+    timeout: Duration,
+    _reader_task: tokio::task::JoinHandle<()>,
+}
+
+impl ProcessPlugin {
+    /// Spawns `manifest.executable`, binds a Unix domain socket for it to
+    /// connect back to, and waits for that connection before returning.
+    pub async fn spawn(manifest: &ProcessManifest) -> AuriaResult<Self> {
+        let socket_path = std::env::temp_dir().join(format!("auria-plugin-{}.sock", manifest.name));
+        let _ = std::fs::remove_file(&socket_path);
+
+        let listener = UnixListener::bind(&socket_path).map_err(|e| {
+            AuriaError::ExecutionError(format!(
+                "failed to bind plugin socket {}: {}",
+                socket_path.display(),
+                e
+            ))
+        })?;
+
+        let mut child = Command::new(&manifest.executable)
+            .args(&manifest.args)
+            .env("AURIA_PLUGIN_SOCKET", &socket_path)
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| {
+                AuriaError::ExecutionError(format!(
+                    "failed to spawn plugin executable {}: {}",
+                    manifest.executable.display(),
+                    e
+                ))
+            })?;
+
+        let (stream, _addr) = tokio::select! {
+            accepted = listener.accept() => accepted.map_err(|e| {
+                AuriaError::ExecutionError(format!("plugin {} did not connect: {}", manifest.name, e))
+            })?,
+            status = child.wait() => {
+                return Err(AuriaError::ExecutionError(format!(
+                    "plugin {} exited before connecting: {:?}",
+                    manifest.name, status
+                )));
+            }
+        };
+
+        let (reader, writer) = stream.into_split();
+        let pending: PendingMap = Arc::new(Mutex::new(HashMap::new()));
+        let reader_task = tokio::spawn(read_loop(manifest.name.clone(), reader, child, pending.clone()));
+
+        Ok(Self {
+            name: manifest.name.clone(),
+            version: manifest.version.clone(),
+            socket_path,
+            writer: Mutex::new(writer),
+            pending,
+            next_id: AtomicU64::new(0),
+            timeout: DEFAULT_CALL_TIMEOUT,
+            _reader_task: reader_task,
+        })
+    }
+
+    async fn call(&self, method: &str) -> AuriaResult<()> {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        let (tx, rx) = oneshot::channel();
+        self.pending.lock().await.insert(id, tx);
+
+        let request = RpcRequest { id, method: method.to_string(), params: () };
+        let body = rmp_serde::to_vec_named(&request)
+            .map_err(|e| AuriaError::ExecutionError(format!("failed to encode RPC request: {}", e)))?;
+        let len = (body.len() as u32).to_be_bytes();
+
+        if let Err(e) = self.write_request(&len, &body).await {
+            self.pending.lock().await.remove(&id);
+            return Err(e);
+        }
+
+        match tokio::time::timeout(self.timeout, rx).await {
+            Ok(Ok(Ok(()))) => Ok(()),
+            Ok(Ok(Err(reason))) => Err(AuriaError::ExecutionError(format!(
+                "plugin {} returned an error for `{}`: {}",
+                self.name, method, reason
+            ))),
+            Ok(Err(_)) => Err(AuriaError::ExecutionError(format!(
+                "plugin {} disconnected while awaiting `{}`",
+                self.name, method
+            ))),
+            Err(_) => {
+                self.pending.lock().await.remove(&id);
+                Err(AuriaError::ExecutionError(format!(
+                    "plugin {} timed out after {:?} on `{}`",
+                    self.name, self.timeout, method
+                )))
+            }
+        }
+    }
+
+    async fn write_request(&self, len: &[u8], body: &[u8]) -> AuriaResult<()> {
+        let mut writer = self.writer.lock().await;
+        writer
+            .write_all(len)
+            .await
+            .map_err(|e| AuriaError::ExecutionError(format!("failed to write to plugin {}: {}", self.name, e)))?;
+        writer
+            .write_all(body)
+            .await
+            .map_err(|e| AuriaError::ExecutionError(format!("failed to write to plugin {}: {}", self.name, e)))?;
+        Ok(())
+    }
+}
+
+impl Drop for ProcessPlugin {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.socket_path);
+    }
+}
+
+/// Reads length-prefixed MessagePack responses off `reader` and completes
+/// the matching pending request. If the child exits first, every request
+/// still waiting is failed rather than left to hang.
+async fn read_loop(
+    plugin_name: String,
+    mut reader: tokio::net::unix::OwnedReadHalf,
+    mut child: Child,
+    pending: PendingMap,
+) {
+    loop {
+        let mut len_buf = [0u8; 4];
+        tokio::select! {
+            read = reader.read_exact(&mut len_buf) => {
+                if read.is_err() {
+                    break;
+                }
+                let len = u32::from_be_bytes(len_buf) as usize;
+                if len > MAX_FRAME_LEN {
+                    let reason = format!(
+                        "plugin {} sent an oversized frame ({} bytes, limit {})",
+                        plugin_name, len, MAX_FRAME_LEN
+                    );
+                    for (_, tx) in pending.lock().await.drain() {
+                        let _ = tx.send(Err(reason.clone()));
+                    }
+                    return;
+                }
+                let mut body = vec![0u8; len];
+                if reader.read_exact(&mut body).await.is_err() {
+                    break;
+                }
+                let Ok(response) = rmp_serde::from_slice::<RpcResponse>(&body) else {
+                    continue;
+                };
+                if let Some(tx) = pending.lock().await.remove(&response.id) {
+                    let _ = tx.send(response.error.map_or(Ok(()), Err));
+                }
+            }
+            status = child.wait() => {
+                let reason = format!("plugin {} process exited unexpectedly: {:?}", plugin_name, status);
+                for (_, tx) in pending.lock().await.drain() {
+                    let _ = tx.send(Err(reason.clone()));
+                }
+                return;
+            }
+        }
+    }
+
+    let reason = format!("plugin {} socket closed unexpectedly", plugin_name);
+    for (_, tx) in pending.lock().await.drain() {
+        let _ = tx.send(Err(reason.clone()));
+    }
+}
+
+#[async_trait::async_trait]
+impl Plugin for ProcessPlugin {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn version(&self) -> &str {
+        &self.version
+    }
+
+    fn plugin_type(&self) -> PluginType {
+        PluginType::Process
+    }
+
+    async fn initialize(&self) -> AuriaResult<()> {
+        self.call("initialize").await
+    }
+
+    async fn shutdown(&self) -> AuriaResult<()> {
+        self.call("shutdown").await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::UnixStream;
+
+    /// A child that outlives the test so `read_loop`'s `child.wait()` branch
+    /// never fires, isolating the behavior under test to the socket side.
+    async fn spawn_long_lived_child() -> Child {
+        Command::new("sleep")
+            .arg("60")
+            .kill_on_drop(true)
+            .spawn()
+            .expect("failed to spawn fixture child process")
+    }
+
+    #[tokio::test]
+    async fn oversized_frame_fails_pending_requests_and_stops_reading() {
+        let (host_side, mut plugin_side) = UnixStream::pair().unwrap();
+        let (reader, _writer) = host_side.into_split();
+        let child = spawn_long_lived_child().await;
+
+        let pending: PendingMap = Arc::new(Mutex::new(HashMap::new()));
+        let (tx, rx) = oneshot::channel();
+        pending.lock().await.insert(1, tx);
+
+        let oversized_len = (MAX_FRAME_LEN as u32) + 1;
+        plugin_side.write_all(&oversized_len.to_be_bytes()).await.unwrap();
+
+        read_loop("test-plugin".to_string(), reader, child, pending).await;
+
+        let result = rx.await.unwrap();
+        assert!(result.unwrap_err().contains("oversized frame"));
+    }
+
+    #[tokio::test]
+    async fn well_formed_frame_completes_the_matching_pending_request() {
+        let (host_side, mut plugin_side) = UnixStream::pair().unwrap();
+        let (reader, _writer) = host_side.into_split();
+        let child = spawn_long_lived_child().await;
+
+        let pending: PendingMap = Arc::new(Mutex::new(HashMap::new()));
+        let (tx, rx) = oneshot::channel();
+        pending.lock().await.insert(7, tx);
+
+        let response = RpcResponse { id: 7, error: None };
+        let body = rmp_serde::to_vec_named(&response).unwrap();
+        plugin_side.write_all(&(body.len() as u32).to_be_bytes()).await.unwrap();
+        plugin_side.write_all(&body).await.unwrap();
+        drop(plugin_side);
+
+        read_loop("test-plugin".to_string(), reader, child, pending).await;
+
+        let result = rx.await.unwrap();
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,124 @@
+// File: signing.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Ed25519 signature verification for native/WASM plugin artifacts,
+//     gated by `PluginConfig::trusted_keys` and `require_signatures`.
+//
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use std::path::{Path, PathBuf};
+
+/// Extension appended to an artifact's path to find its detached
+/// signature, e.g. `backend.so` is signed by `backend.so.sig`.
+const SIGNATURE_SUFFIX: &str = ".sig";
+
+/// Path to the detached signature file for `artifact`.
+fn signature_path(artifact: &Path) -> PathBuf {
+    let mut name = artifact.as_os_str().to_owned();
+    name.push(SIGNATURE_SUFFIX);
+    PathBuf::from(name)
+}
+
+/// Parses the hex-encoded ed25519 public keys stored in
+/// `PluginConfig::trusted_keys`, silently skipping any that don't parse so
+/// one malformed entry doesn't take down the whole trust store.
+pub fn parse_trusted_keys(raw: &[String]) -> Vec<VerifyingKey> {
+    raw.iter().filter_map(|key| decode_key(key)).collect()
+}
+
+fn decode_key(hex_key: &str) -> Option<VerifyingKey> {
+    let bytes = hex_decode(hex_key)?;
+    let bytes: [u8; 32] = bytes.try_into().ok()?;
+    VerifyingKey::from_bytes(&bytes).ok()
+}
+
+/// Verifies `artifact`'s detached `.sig` file against `trusted_keys`.
+///
+/// `Ok(())` if any trusted key verifies the signature; `Err` with a
+/// human-readable reason otherwise (unreadable artifact, missing or
+/// malformed signature file, or no trusted key matching).
+pub fn verify_artifact(artifact: &Path, trusted_keys: &[VerifyingKey]) -> Result<(), String> {
+    let artifact_bytes = std::fs::read(artifact)
+        .map_err(|e| format!("failed to read artifact {}: {}", artifact.display(), e))?;
+
+    let sig_path = signature_path(artifact);
+    let sig_bytes = std::fs::read(&sig_path)
+        .map_err(|e| format!("missing signature file {}: {}", sig_path.display(), e))?;
+    let sig_bytes: [u8; 64] = sig_bytes.try_into().map_err(|_| {
+        format!("signature file {} is not a raw 64-byte ed25519 signature", sig_path.display())
+    })?;
+    let signature = Signature::from_bytes(&sig_bytes);
+
+    if trusted_keys.is_empty() {
+        return Err("no trusted keys configured".to_string());
+    }
+
+    if trusted_keys.iter().any(|key| key.verify(&artifact_bytes, &signature).is_ok()) {
+        Ok(())
+    } else {
+        Err(format!("signature on {} does not match any trusted key", artifact.display()))
+    }
+}
+
+fn hex_decode(s: &str) -> Option<Vec<u8>> {
+    if s.len() % 2 != 0 {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    fn write_signed_artifact(dir: &Path, signing_key: &SigningKey) -> PathBuf {
+        std::fs::create_dir_all(dir).unwrap();
+        let artifact_path = dir.join("plugin.so");
+        std::fs::write(&artifact_path, b"plugin bytes").unwrap();
+        let signature = signing_key.sign(b"plugin bytes");
+        std::fs::write(signature_path(&artifact_path), signature.to_bytes()).unwrap();
+        artifact_path
+    }
+
+    #[test]
+    fn verifies_a_correctly_signed_artifact() {
+        let dir = std::env::temp_dir().join(format!("auria-signing-test-{}", std::process::id()));
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let artifact_path = write_signed_artifact(&dir, &signing_key);
+
+        let trusted = vec![signing_key.verifying_key()];
+        assert!(verify_artifact(&artifact_path, &trusted).is_ok());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn rejects_a_signature_from_an_untrusted_key() {
+        let dir = std::env::temp_dir().join(format!("auria-signing-test-untrusted-{}", std::process::id()));
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let artifact_path = write_signed_artifact(&dir, &signing_key);
+
+        let other_key = SigningKey::from_bytes(&[9u8; 32]);
+        let trusted = vec![other_key.verifying_key()];
+        assert!(verify_artifact(&artifact_path, &trusted).is_err());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn rejects_a_missing_signature_file() {
+        let dir = std::env::temp_dir().join(format!("auria-signing-test-missing-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let artifact_path = dir.join("plugin.so");
+        std::fs::write(&artifact_path, b"plugin bytes").unwrap();
+
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let trusted = vec![signing_key.verifying_key()];
+        assert!(verify_artifact(&artifact_path, &trusted).is_err());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,82 @@
+// File: state.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Explicit plugin lifecycle state machine. Replaces the implicit
+//     "registered + enabled bool" model with a state operators can inspect,
+//     including why a plugin failed to come up.
+//
+use serde::{Deserialize, Serialize};
+
+/// Where a registered plugin currently is in its lifecycle.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PluginState {
+    /// Registered but `initialize` has not been called yet.
+    Registered,
+    /// `initialize` is in flight.
+    Initializing,
+    /// `initialize` succeeded; the plugin is live.
+    Active,
+    /// Manually taken out of service via `disable`.
+    Disabled,
+    /// `initialize` returned an error, recorded here. Must be explicitly
+    /// reset before the plugin can be initialized again.
+    Failed(String),
+    /// Torn down via `unregister`. Plugins in this state are not kept in
+    /// the registry; it exists so callers holding a stale snapshot can
+    /// recognize it.
+    Unloaded,
+}
+
+impl PluginState {
+    /// Whether moving from `self` to `next` is a legal lifecycle
+    /// transition. `Initializing` is reachable only from `Registered`
+    /// (never directly from `Failed`, which must go through an explicit
+    /// reset back to `Registered` first).
+    pub fn can_transition_to(&self, next: &PluginState) -> bool {
+        use PluginState::*;
+        matches!(
+            (self, next),
+            (Registered, Initializing)
+                | (Initializing, Active)
+                | (Initializing, Failed(_))
+                | (Failed(_), Registered)
+                | (Active, Disabled)
+                | (Disabled, Active)
+                | (Active, Unloaded)
+                | (Disabled, Unloaded)
+                | (Registered, Unloaded)
+        )
+    }
+}
+
+impl std::fmt::Display for PluginState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PluginState::Registered => write!(f, "registered"),
+            PluginState::Initializing => write!(f, "initializing"),
+            PluginState::Active => write!(f, "active"),
+            PluginState::Disabled => write!(f, "disabled"),
+            PluginState::Failed(reason) => write!(f, "failed: {}", reason),
+            PluginState::Unloaded => write!(f, "unloaded"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn initializing_only_follows_registered() {
+        assert!(PluginState::Registered.can_transition_to(&PluginState::Initializing));
+        assert!(!PluginState::Active.can_transition_to(&PluginState::Initializing));
+        assert!(!PluginState::Failed("boom".to_string()).can_transition_to(&PluginState::Initializing));
+    }
+
+    #[test]
+    fn failed_requires_explicit_reset() {
+        let failed = PluginState::Failed("boom".to_string());
+        assert!(failed.can_transition_to(&PluginState::Registered));
+        assert!(!failed.can_transition_to(&PluginState::Active));
+    }
+}
@@ -0,0 +1,398 @@
+// File: wasm.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     WASM-sandboxed plugin backend. Lets third-party routing/middleware
+//     logic run as a `wasm32-wasi` guest instead of a trusted native shared
+//     library, with the host enforcing memory and time limits on it.
+//
+use crate::{Plugin, PluginType};
+use auria_core::{AuriaError, AuriaResult};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Duration;
+use wasmtime::{Caller, Config, Engine, Linker, Module, Store, StoreLimits, StoreLimitsBuilder};
+use wasmtime_wasi::{WasiCtx, WasiCtxBuilder};
+
+/// Guest exports an AURIA WASM plugin is expected to provide. `initialize`
+/// and `shutdown` take no arguments and return a status code (0 = success);
+/// `name`/`version` take no arguments and return a `(ptr, len)` pair
+/// locating a UTF-8 string in the guest's exported `memory`.
+const EXPORT_NAME: &str = "name";
+const EXPORT_VERSION: &str = "version";
+const EXPORT_INITIALIZE: &str = "initialize";
+const EXPORT_SHUTDOWN: &str = "shutdown";
+
+/// Default resource caps applied to every guest instance, overridable via
+/// [`WasmPluginConfig`].
+const DEFAULT_MEMORY_LIMIT_BYTES: usize = 64 * 1024 * 1024;
+const DEFAULT_CALL_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How often the background epoch ticker increments the engine's epoch.
+/// Wasmtime's epoch-interruption checks run at loop back-edges and calls,
+/// so this is the granularity at which a stuck guest export is noticed and
+/// trapped; a call's deadline is expressed in units of this interval.
+const EPOCH_TICK_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Hard cap on a guest-reported string length, independent of the guest's
+/// own memory size. `name`/`version` are tiny identifiers; nothing
+/// legitimate needs anywhere near this much.
+const MAX_GUEST_STRING_LEN: usize = 4096;
+
+/// Hard cap on a single `auria_host::log` message or `config_get` key/value,
+/// independent of the guest's own memory size.
+const MAX_HOST_CALL_BYTES: usize = 64 * 1024;
+
+/// Per-plugin resource limits and host-function allowlist.
+#[derive(Clone, Debug)]
+pub struct WasmPluginConfig {
+    pub memory_limit_bytes: usize,
+    pub call_timeout: Duration,
+    /// Read-only key/value config the guest can look up via the
+    /// `auria_host::config_get` host function.
+    pub host_config: HashMap<String, String>,
+}
+
+impl Default for WasmPluginConfig {
+    fn default() -> Self {
+        Self {
+            memory_limit_bytes: DEFAULT_MEMORY_LIMIT_BYTES,
+            call_timeout: DEFAULT_CALL_TIMEOUT,
+            host_config: HashMap::new(),
+        }
+    }
+}
+
+struct HostState {
+    wasi: WasiCtx,
+    limits: StoreLimits,
+    host_config: HashMap<String, String>,
+}
+
+/// A plugin backed by a sandboxed `wasm32-wasi` module.
+///
+/// Values cross the boundary as plain integers (status codes) and raw UTF-8
+/// strings addressed by `(ptr, len)` pairs in guest memory; no MessagePack
+/// or JSON encoding is applied to them. The
+/// guest may call back into a small set of host functions (logging, config
+/// lookup) registered on the `Linker` at construction time.
+pub struct WasmPlugin {
+    name: String,
+    version: String,
+    engine: Engine,
+    module: Module,
+    config: WasmPluginConfig,
+    store: Mutex<Store<HostState>>,
+    _epoch_ticker: EpochTicker,
+}
+
+impl WasmPlugin {
+    /// Compiles and instantiates `path` as a guest module, querying its
+    /// `name`/`version` exports up front so the synchronous [`Plugin::name`]
+    /// and [`Plugin::version`] can return cached values.
+    pub async fn load(path: &Path, config: WasmPluginConfig) -> AuriaResult<Self> {
+        let mut engine_config = Config::new();
+        engine_config.async_support(true);
+        engine_config.epoch_interruption(true);
+        let engine = Engine::new(&engine_config)
+            .map_err(|e| AuriaError::ExecutionError(format!("failed to create wasm engine: {}", e)))?;
+
+        let bytes = std::fs::read(path)
+            .map_err(|e| AuriaError::ExecutionError(format!("failed to read wasm plugin {}: {}", path.display(), e)))?;
+        let module = Module::new(&engine, &bytes)
+            .map_err(|e| AuriaError::ExecutionError(format!("failed to compile wasm plugin {}: {}", path.display(), e)))?;
+
+        let store = new_store(&engine, &config)?;
+        let epoch_ticker = spawn_epoch_ticker(engine.clone());
+        let mut plugin = Self {
+            name: String::new(),
+            version: String::new(),
+            engine,
+            module,
+            config,
+            store: Mutex::new(store),
+            _epoch_ticker: epoch_ticker,
+        };
+
+        plugin.name = plugin.call_string_export(EXPORT_NAME).await?;
+        plugin.version = plugin.call_string_export(EXPORT_VERSION).await?;
+
+        Ok(plugin)
+    }
+
+    fn linker(&self) -> AuriaResult<Linker<HostState>> {
+        let mut linker = Linker::new(&self.engine);
+        wasmtime_wasi::add_to_linker_async(&mut linker)
+            .map_err(|e| AuriaError::ExecutionError(format!("failed to register wasi host functions: {}", e)))?;
+
+        // Host functions the guest can call back into: logging and
+        // read-only config lookup. `log` reads a UTF-8 message out of guest
+        // memory at `(ptr, len)` and emits it; `config_get` reads a UTF-8
+        // key the same way and, if found in `WasmPluginConfig::host_config`,
+        // writes the value into guest memory at `(out_ptr, out_cap)`.
+        linker
+            .func_wrap("auria_host", "log", |mut caller: Caller<'_, HostState>, level: i32, ptr: i32, len: i32| {
+                let Some(memory) = caller.get_export("memory").and_then(|e| e.into_memory()) else {
+                    return;
+                };
+                let Ok(bytes) = read_bounded(&memory, &caller, ptr, len, MAX_HOST_CALL_BYTES) else {
+                    return;
+                };
+                eprintln!("[wasm plugin log level={}] {}", level, String::from_utf8_lossy(&bytes));
+            })
+            .map_err(|e| AuriaError::ExecutionError(format!("failed to register host log function: {}", e)))?;
+        linker
+            .func_wrap(
+                "auria_host",
+                "config_get",
+                |mut caller: Caller<'_, HostState>, key_ptr: i32, key_len: i32, out_ptr: i32, out_cap: i32| -> i32 {
+                    let Some(memory) = caller.get_export("memory").and_then(|e| e.into_memory()) else {
+                        return -1;
+                    };
+                    let Ok(key_bytes) = read_bounded(&memory, &caller, key_ptr, key_len, MAX_HOST_CALL_BYTES) else {
+                        return -1;
+                    };
+                    let Ok(key) = String::from_utf8(key_bytes) else {
+                        return -1;
+                    };
+                    let Some(value) = caller.data().host_config.get(&key).cloned() else {
+                        return -1;
+                    };
+                    if out_cap < 0 || value.len() > out_cap as usize {
+                        return -1;
+                    }
+                    if out_ptr < 0 || memory.write(&mut caller, out_ptr as usize, value.as_bytes()).is_err() {
+                        return -1;
+                    }
+                    value.len() as i32
+                },
+            )
+            .map_err(|e| AuriaError::ExecutionError(format!("failed to register host config function: {}", e)))?;
+
+        Ok(linker)
+    }
+
+    async fn call_string_export(&self, export: &str) -> AuriaResult<String> {
+        let linker = self.linker()?;
+        let mut store = self.store.lock().expect("wasm plugin store poisoned");
+        store.set_epoch_deadline(epoch_ticks_for(self.config.call_timeout));
+
+        let instance = linker
+            .instantiate_async(&mut *store, &self.module)
+            .await
+            .map_err(|e| AuriaError::ExecutionError(format!("failed to instantiate wasm plugin: {}", e)))?;
+
+        let func = instance
+            .get_typed_func::<(), (i32, i32)>(&mut *store, export)
+            .map_err(|e| AuriaError::ExecutionError(format!("wasm plugin missing export `{}`: {}", export, e)))?;
+        let (ptr, len) = func
+            .call_async(&mut *store, ())
+            .await
+            .map_err(|e| AuriaError::ExecutionError(format!("wasm plugin export `{}` trapped or timed out: {}", export, e)))?;
+
+        read_guest_string(&instance, &mut store, ptr, len)
+    }
+
+    async fn call_status_export(&self, export: &str) -> AuriaResult<()> {
+        let linker = self.linker()?;
+        let mut store = self.store.lock().expect("wasm plugin store poisoned");
+        store.set_epoch_deadline(epoch_ticks_for(self.config.call_timeout));
+
+        let instance = linker
+            .instantiate_async(&mut *store, &self.module)
+            .await
+            .map_err(|e| AuriaError::ExecutionError(format!("failed to instantiate wasm plugin: {}", e)))?;
+
+        let func = instance
+            .get_typed_func::<(), i32>(&mut *store, export)
+            .map_err(|e| AuriaError::ExecutionError(format!("wasm plugin missing export `{}`: {}", export, e)))?;
+
+        let status = func
+            .call_async(&mut *store, ())
+            .await
+            .map_err(|e| AuriaError::ExecutionError(format!("wasm plugin export `{}` trapped or timed out: {}", export, e)))?;
+
+        if status == 0 {
+            Ok(())
+        } else {
+            Err(AuriaError::ExecutionError(format!("wasm plugin export `{}` returned status {}", export, status)))
+        }
+    }
+}
+
+/// Owns the background task that periodically bumps a `WasmPlugin`'s engine
+/// epoch, which is what actually makes `set_epoch_deadline` enforce a
+/// wall-clock timeout on guest calls (see [`epoch_ticks_for`]). Dropping it
+/// stops the ticker.
+struct EpochTicker {
+    stop: Option<tokio::sync::oneshot::Sender<()>>,
+    task: Option<tokio::task::JoinHandle<()>>,
+}
+
+impl Drop for EpochTicker {
+    fn drop(&mut self) {
+        if let Some(stop) = self.stop.take() {
+            let _ = stop.send(());
+        }
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
+}
+
+fn spawn_epoch_ticker(engine: Engine) -> EpochTicker {
+    let (stop_tx, mut stop_rx) = tokio::sync::oneshot::channel();
+    let task = tokio::spawn(async move {
+        let mut interval = tokio::time::interval(EPOCH_TICK_INTERVAL);
+        loop {
+            tokio::select! {
+                _ = interval.tick() => engine.increment_epoch(),
+                _ = &mut stop_rx => break,
+            }
+        }
+    });
+    EpochTicker { stop: Some(stop_tx), task: Some(task) }
+}
+
+/// Converts a wall-clock timeout into the number of [`EPOCH_TICK_INTERVAL`]
+/// ticks `set_epoch_deadline` should be given, rounding up so a call is never
+/// trapped before its configured timeout has actually elapsed.
+fn epoch_ticks_for(timeout: Duration) -> u64 {
+    let interval_nanos = EPOCH_TICK_INTERVAL.as_nanos().max(1);
+    let ticks = (timeout.as_nanos() + interval_nanos - 1) / interval_nanos;
+    (ticks as u64).max(1)
+}
+
+fn new_store(engine: &Engine, config: &WasmPluginConfig) -> AuriaResult<Store<HostState>> {
+    let wasi = WasiCtxBuilder::new().build();
+    let limits = StoreLimitsBuilder::new()
+        .memory_size(config.memory_limit_bytes)
+        .build();
+    let host_config = config.host_config.clone();
+    let mut store = Store::new(engine, HostState { wasi, limits, host_config });
+    store.limiter(|state| &mut state.limits);
+    store.epoch_deadline_trap();
+    Ok(store)
+}
+
+/// Reads `len` bytes at `ptr` out of `memory`, rejecting a negative
+/// pointer/length, a length over `max_len`, or a range that runs past the
+/// instance's actual memory size — the guest's `(ptr, len)` pair is
+/// untrusted input and must never size a host-side allocation or read
+/// directly.
+fn read_bounded(
+    memory: &wasmtime::Memory,
+    store: impl wasmtime::AsContext,
+    ptr: i32,
+    len: i32,
+    max_len: usize,
+) -> Result<Vec<u8>, String> {
+    if ptr < 0 || len < 0 {
+        return Err(format!("invalid pointer/length ({}, {})", ptr, len));
+    }
+    let (ptr, len) = (ptr as usize, len as usize);
+
+    let memory_size = memory.data_size(&store);
+    let in_bounds = ptr.checked_add(len).map_or(false, |end| end <= memory_size);
+    if len > max_len || !in_bounds {
+        return Err(format!(
+            "{} bytes at {} exceeds the {}-byte limit or the {}-byte memory",
+            len, ptr, max_len, memory_size
+        ));
+    }
+
+    let mut buf = vec![0u8; len];
+    memory.read(&store, ptr, &mut buf).map_err(|e| e.to_string())?;
+    Ok(buf)
+}
+
+fn read_guest_string(
+    instance: &wasmtime::Instance,
+    mut store: impl wasmtime::AsContextMut,
+    ptr: i32,
+    len: i32,
+) -> AuriaResult<String> {
+    let memory = instance
+        .get_memory(&mut store, "memory")
+        .ok_or_else(|| AuriaError::ExecutionError("wasm plugin does not export linear memory".to_string()))?;
+
+    let buf = read_bounded(&memory, &store, ptr, len, MAX_GUEST_STRING_LEN)
+        .map_err(|e| AuriaError::ExecutionError(format!("wasm plugin returned an invalid string: {}", e)))?;
+    String::from_utf8(buf).map_err(|e| AuriaError::ExecutionError(format!("wasm plugin returned non-utf8 string: {}", e)))
+}
+
+#[async_trait::async_trait]
+impl Plugin for WasmPlugin {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn version(&self) -> &str {
+        &self.version
+    }
+
+    fn plugin_type(&self) -> PluginType {
+        PluginType::Wasm
+    }
+
+    async fn initialize(&self) -> AuriaResult<()> {
+        self.call_status_export(EXPORT_INITIALIZE).await
+    }
+
+    async fn shutdown(&self) -> AuriaResult<()> {
+        self.call_status_export(EXPORT_SHUTDOWN).await
+    }
+}
+
+/// Whether `path` looks like a WASM plugin candidate for
+/// [`crate::PluginManager::load_plugins_from_dir`].
+pub fn is_wasm_plugin_path(path: &Path) -> bool {
+    path.extension().map_or(false, |ext| ext == "wasm")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MEMORY_ONLY_WAT: &str = r#"(module (memory (export "memory") 1))"#;
+
+    /// A bare one-page-memory guest instance, just enough to exercise
+    /// `read_bounded`'s bounds checking against real `wasmtime::Memory`.
+    fn memory_fixture() -> (Store<()>, wasmtime::Memory) {
+        let engine = Engine::default();
+        let module = Module::new(&engine, MEMORY_ONLY_WAT).unwrap();
+        let mut store = Store::new(&engine, ());
+        let linker: Linker<()> = Linker::new(&engine);
+        let instance = linker.instantiate(&mut store, &module).unwrap();
+        let memory = instance.get_memory(&mut store, "memory").unwrap();
+        (store, memory)
+    }
+
+    #[test]
+    fn read_bounded_rejects_a_negative_pointer_or_length() {
+        let (store, memory) = memory_fixture();
+        assert!(read_bounded(&memory, &store, -1, 4, 4096).is_err());
+        assert!(read_bounded(&memory, &store, 0, -1, 4096).is_err());
+    }
+
+    #[test]
+    fn read_bounded_rejects_a_length_over_the_cap() {
+        let (store, memory) = memory_fixture();
+        assert!(read_bounded(&memory, &store, 0, 5000, 4096).is_err());
+    }
+
+    #[test]
+    fn read_bounded_rejects_a_range_past_the_end_of_memory() {
+        let (store, memory) = memory_fixture();
+        let memory_size = memory.data_size(&store) as i32;
+        assert!(read_bounded(&memory, &store, memory_size - 1, 10, 4096).is_err());
+    }
+
+    #[test]
+    fn read_bounded_reads_an_in_bounds_range() {
+        let (mut store, memory) = memory_fixture();
+        memory.write(&mut store, 0, b"hello").unwrap();
+        let bytes = read_bounded(&memory, &store, 0, 5, 4096).unwrap();
+        assert_eq!(bytes, b"hello");
+    }
+}
@@ -0,0 +1,323 @@
+// File: watcher.rs - This file is part of AURIA
+// Copyright (c) 2026 AURIA Developers and Contributors
+// Description:
+//     Hot-reload support for `PluginConfig::enable_hot_reload`. Watches
+//     each configured plugin directory with `notify` and loads, reloads, or
+//     unregisters the corresponding plugin as files appear, change, or
+//     disappear. Native/WASM artifacts are held to the same
+//     `trusted_keys`/`require_signatures` policy as
+//     `PluginManager::load_plugins_from_dir`.
+//
+use crate::{native, signing, wasm, PluginRegistry};
+use auria_core::AuriaResult;
+use ed25519_dalek::VerifyingKey;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, Mutex};
+
+/// How long to wait after the last filesystem event on a path before acting
+/// on it, so a burst of writes from a compiler/linker only triggers one
+/// reload.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Owns the background watcher task for a `PluginManager`'s configured
+/// `plugin_dirs`. Dropping it stops the watcher and its task.
+pub struct HotReloadWatcher {
+    _watcher: RecommendedWatcher,
+    stop: Option<tokio::sync::oneshot::Sender<()>>,
+    task: Option<tokio::task::JoinHandle<()>>,
+}
+
+impl Drop for HotReloadWatcher {
+    fn drop(&mut self) {
+        if let Some(stop) = self.stop.take() {
+            let _ = stop.send(());
+        }
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
+}
+
+/// Signature verification policy applied to hot-reloaded native/WASM
+/// artifacts, mirroring `PluginConfig::trusted_keys`/`require_signatures`.
+struct SignaturePolicy {
+    trusted_keys: Vec<VerifyingKey>,
+    require_signatures: bool,
+}
+
+/// Starts watching `dirs` for plugin changes, reloading into `registry` as
+/// they happen. Native/WASM artifacts are verified against `trusted_keys`
+/// before being loaded, same as `PluginManager::load_plugins_from_dir`; if
+/// `require_signatures` is set, a missing or invalid signature is skipped
+/// rather than loaded.
+pub fn watch(
+    dirs: Vec<PathBuf>,
+    registry: Arc<PluginRegistry>,
+    trusted_keys: Vec<VerifyingKey>,
+    require_signatures: bool,
+) -> AuriaResult<HotReloadWatcher> {
+    let policy = Arc::new(SignaturePolicy { trusted_keys, require_signatures });
+    let (tx, mut rx) = mpsc::unbounded_channel::<PathBuf>();
+
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        if let Ok(event) = event {
+            for path in event.paths {
+                let _ = tx.send(path);
+            }
+        }
+    })
+    .map_err(|e| auria_core::AuriaError::ExecutionError(format!("failed to create plugin directory watcher: {}", e)))?;
+
+    for dir in &dirs {
+        if dir.exists() {
+            let _ = watcher.watch(dir, RecursiveMode::NonRecursive);
+        }
+    }
+
+    let (stop_tx, mut stop_rx) = tokio::sync::oneshot::channel();
+    let loaded_paths: Arc<Mutex<HashMap<PathBuf, String>>> = Arc::new(Mutex::new(HashMap::new()));
+
+    let task = tokio::spawn(async move {
+        let mut pending: HashMap<PathBuf, tokio::time::Instant> = HashMap::new();
+
+        loop {
+            let sleep = tokio::time::sleep(DEBOUNCE);
+            tokio::pin!(sleep);
+
+            tokio::select! {
+                _ = &mut stop_rx => break,
+                path = rx.recv() => {
+                    match path {
+                        Some(path) => { pending.insert(path, tokio::time::Instant::now()); }
+                        None => break,
+                    }
+                }
+                _ = &mut sleep => {
+                    for path in coalesce_ready(&mut pending, tokio::time::Instant::now()) {
+                        handle_change(&path, &registry, &loaded_paths, &policy).await;
+                    }
+                }
+            }
+        }
+    });
+
+    Ok(HotReloadWatcher { _watcher: watcher, stop: Some(stop_tx), task: Some(task) })
+}
+
+/// Drains every entry of `pending` that has been quiet for at least
+/// `DEBOUNCE` as of `now`, so a burst of filesystem events on the same path
+/// collapses into a single reload.
+fn coalesce_ready(
+    pending: &mut HashMap<PathBuf, tokio::time::Instant>,
+    now: tokio::time::Instant,
+) -> Vec<PathBuf> {
+    let ready: Vec<PathBuf> = pending
+        .iter()
+        .filter(|(_, seen)| now.duration_since(**seen) >= DEBOUNCE)
+        .map(|(path, _)| path.clone())
+        .collect();
+    for path in &ready {
+        pending.remove(path);
+    }
+    ready
+}
+
+/// What a newly-loaded artifact at `path` should do to the plugin previously
+/// loaded from the same path, if any.
+#[derive(Debug, PartialEq, Eq)]
+enum ReloadAction {
+    /// No plugin was previously loaded from this path.
+    Register,
+    /// A different plugin was previously loaded from this path; it must be
+    /// unregistered before the new one is registered.
+    UnregisterThenRegister,
+    /// The same plugin (by name) was previously loaded from this path; swap
+    /// it in place so dependents keep their existing registration.
+    Replace,
+}
+
+fn reload_action(previous_name: Option<&str>, new_name: &str) -> ReloadAction {
+    match previous_name {
+        Some(old) if old == new_name => ReloadAction::Replace,
+        Some(_) => ReloadAction::UnregisterThenRegister,
+        None => ReloadAction::Register,
+    }
+}
+
+async fn handle_change(
+    path: &Path,
+    registry: &Arc<PluginRegistry>,
+    loaded_paths: &Arc<Mutex<HashMap<PathBuf, String>>>,
+    policy: &SignaturePolicy,
+) {
+    if !path.exists() {
+        let removed_name = loaded_paths.lock().await.remove(path);
+        if let Some(name) = removed_name {
+            let _ = registry.unregister(&name).await;
+        }
+        return;
+    }
+
+    if native::is_native_plugin_path(path) {
+        reload_native(path, registry, loaded_paths, policy).await;
+    } else if wasm::is_wasm_plugin_path(path) {
+        reload_wasm(path, registry, loaded_paths, policy).await;
+    }
+}
+
+async fn reload_native(
+    path: &Path,
+    registry: &Arc<PluginRegistry>,
+    loaded_paths: &Arc<Mutex<HashMap<PathBuf, String>>>,
+    policy: &SignaturePolicy,
+) {
+    // Check the signature before the artifact is ever loaded, same as
+    // `PluginManager::load_plugins_from_dir`: `load_native_plugin` dlopens
+    // the shared library and calls its exported constructor, both of which
+    // run arbitrary code from the artifact.
+    let verified = signing::verify_artifact(path, &policy.trusted_keys);
+    if policy.require_signatures && verified.is_err() {
+        return;
+    }
+
+    // Safety: only files inside a configured plugin directory are watched.
+    let Ok(loaded) = (unsafe { native::load_native_plugin(path) }) else {
+        return;
+    };
+    if loaded.plugin.initialize().await.is_err() {
+        // Leave whatever was previously registered at this path running.
+        return;
+    }
+
+    let name = loaded.plugin.name().to_string();
+    let previous_name = loaded_paths.lock().await.get(path).cloned();
+
+    // `loaded.plugin` has already been initialized above, so every branch
+    // below records it as `PluginState::Active` up front rather than
+    // registering it `Registered` and driving `initialize` a second time
+    // through the registry.
+    let action = reload_action(previous_name.as_deref(), &name);
+    let result = match (action, &previous_name) {
+        (ReloadAction::Replace, _) => registry.replace_native(&name, loaded.plugin, loaded.library, verified).await,
+        (ReloadAction::UnregisterThenRegister, Some(old_name)) => {
+            let _ = registry.unregister(old_name).await;
+            registry.register_native_active(loaded.plugin, loaded.library, verified).await
+        }
+        _ => registry.register_native_active(loaded.plugin, loaded.library, verified).await,
+    };
+
+    if result.is_ok() {
+        loaded_paths.lock().await.insert(path.to_path_buf(), name.clone());
+        reinitialize_dependents(registry, &name).await;
+    }
+}
+
+async fn reload_wasm(
+    path: &Path,
+    registry: &Arc<PluginRegistry>,
+    loaded_paths: &Arc<Mutex<HashMap<PathBuf, String>>>,
+    policy: &SignaturePolicy,
+) {
+    // Same ordering requirement as `reload_native` above: check the
+    // signature before the module is compiled/instantiated.
+    let verified = signing::verify_artifact(path, &policy.trusted_keys);
+    if policy.require_signatures && verified.is_err() {
+        return;
+    }
+
+    let Ok(plugin) = wasm::WasmPlugin::load(path, wasm::WasmPluginConfig::default()).await else {
+        return;
+    };
+    if plugin.initialize().await.is_err() {
+        return;
+    }
+
+    let name = plugin.name().to_string();
+    if let Some(old_name) = loaded_paths.lock().await.get(path).cloned() {
+        let _ = registry.unregister(&old_name).await;
+    }
+
+    // Already initialized above: record it as `Active` directly instead of
+    // registering `Registered` and re-initializing through the registry.
+    if registry.register_boxed_active(Box::new(plugin), verified).await.is_ok() {
+        loaded_paths.lock().await.insert(path.to_path_buf(), name.clone());
+        reinitialize_dependents(registry, &name).await;
+    }
+}
+
+/// Re-initializes every plugin that depends on `name`, in dependency order,
+/// so a hot-reloaded plugin's dependents pick up the new instance.
+async fn reinitialize_dependents(registry: &Arc<PluginRegistry>, name: &str) {
+    let Ok(order) = registry.resolve_load_order().await else {
+        return;
+    };
+    let dependents: std::collections::HashSet<String> = registry.dependents_of(name).await.into_iter().collect();
+
+    for candidate in order {
+        if dependents.contains(&candidate) {
+            let _ = registry.reinitialize(&candidate).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coalesce_ready_skips_paths_seen_too_recently() {
+        let now = tokio::time::Instant::now();
+        let mut pending = HashMap::new();
+        pending.insert(PathBuf::from("/plugins/fresh.so"), now);
+
+        let ready = coalesce_ready(&mut pending, now);
+
+        assert!(ready.is_empty());
+        assert!(pending.contains_key(&PathBuf::from("/plugins/fresh.so")));
+    }
+
+    #[test]
+    fn coalesce_ready_drains_paths_quiet_for_at_least_the_debounce_window() {
+        let seen = tokio::time::Instant::now();
+        let now = seen + DEBOUNCE;
+        let mut pending = HashMap::new();
+        pending.insert(PathBuf::from("/plugins/stable.so"), seen);
+
+        let ready = coalesce_ready(&mut pending, now);
+
+        assert_eq!(ready, vec![PathBuf::from("/plugins/stable.so")]);
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn coalesce_ready_only_drains_events_past_their_own_debounce_window() {
+        let now = tokio::time::Instant::now();
+        let mut pending = HashMap::new();
+        pending.insert(PathBuf::from("/plugins/old.so"), now - DEBOUNCE);
+        pending.insert(PathBuf::from("/plugins/new.so"), now);
+
+        let ready = coalesce_ready(&mut pending, now);
+
+        assert_eq!(ready, vec![PathBuf::from("/plugins/old.so")]);
+        assert!(pending.contains_key(&PathBuf::from("/plugins/new.so")));
+    }
+
+    #[test]
+    fn reload_action_registers_when_nothing_was_previously_loaded() {
+        assert_eq!(reload_action(None, "backend"), ReloadAction::Register);
+    }
+
+    #[test]
+    fn reload_action_replaces_when_the_reloaded_plugin_kept_its_name() {
+        assert_eq!(reload_action(Some("backend"), "backend"), ReloadAction::Replace);
+    }
+
+    #[test]
+    fn reload_action_unregisters_the_old_plugin_when_the_name_changed() {
+        assert_eq!(reload_action(Some("old-backend"), "new-backend"), ReloadAction::UnregisterThenRegister);
+    }
+}